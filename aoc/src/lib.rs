@@ -0,0 +1,55 @@
+use common::Day;
+
+/// All solved days of the season.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day_01::Day01>(),
+        Day::new::<day_02::Day02>(),
+        Day::new::<day_03::Day03>(),
+        Day::new::<day_04::Day04>(),
+        Day::new::<day_05::Day05>(),
+        Day::new::<day_06::Day06>(),
+        Day::new::<day_07::Day07>().configurable::<day_07::Day07>(),
+        Day::new::<day_08::Day08>().rendered::<day_08::Day08>(),
+        Day::new::<day_09::Day09>()
+            .simulated::<day_09::Day09>()
+            .configurable::<day_09::Day09>(),
+        Day::new::<day_10::Day10>(),
+        Day::new::<day_11::Day11>().configurable::<day_11::Day11>(),
+        Day::new::<day_12::Day12>()
+            .rendered::<day_12::Day12>()
+            .instrumented::<day_12::Day12>(),
+        Day::new::<day_13::Day13>(),
+        Day::new::<day_14::Day14>()
+            .simulated::<day_14::Day14>()
+            .rendered::<day_14::Day14>(),
+        Day::new::<day_15::Day15>()
+            .resumable::<day_15::Day15>()
+            .cancellable::<day_15::Day15>()
+            .configurable::<day_15::Day15>(),
+        Day::new::<day_16::Day16>()
+            .instrumented::<day_16::Day16>()
+            .resumable::<day_16::Day16>()
+            .cancellable::<day_16::Day16>()
+            .configurable::<day_16::Day16>(),
+        Day::new::<day_17::Day17>()
+            .simulated::<day_17::Day17>()
+            .rendered::<day_17::Day17>(),
+        Day::new::<day_18::Day18>(),
+        Day::new::<day_19::Day19>()
+            .instrumented::<day_19::Day19>()
+            .resumable::<day_19::Day19>()
+            .cancellable::<day_19::Day19>()
+            .configurable::<day_19::Day19>(),
+        Day::new::<day_20::Day20>()
+            .cancellable::<day_20::Day20>()
+            .configurable::<day_20::Day20>(),
+        Day::new::<day_21::Day21>(),
+        Day::new::<day_22::Day22>().simulated::<day_22::Day22>(),
+        Day::new::<day_23::Day23>()
+            .simulated::<day_23::Day23>()
+            .rendered::<day_23::Day23>(),
+        Day::new::<day_24::Day24>(),
+        Day::new::<day_25::Day25>(),
+    ]
+}
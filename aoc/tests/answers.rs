@@ -0,0 +1,84 @@
+//! Runs every day against all inputs listed in its `data/answers.toml`, with their parameters in `aoc.toml`, and compares
+//! with the known answers.
+
+use common::{
+    answers::Answers,
+    config::Config,
+    input::{data_dir, Source},
+    Part,
+};
+
+fn check(number: u8) {
+    let days = aoc::days();
+    let day = days
+        .iter()
+        .find(|day| day.number == number)
+        .expect("Day is not part of the runner");
+    let answers = Answers::load(number).unwrap();
+    let config = Config::load().unwrap();
+    assert!(
+        answers.iter().next().is_some(),
+        "Day {number:02} has no known answers"
+    );
+
+    let mut failures = Vec::new();
+    for (name, expected) in answers.iter() {
+        let input = Source::File(data_dir(number).join(format!("{name}.txt")))
+            .read(number)
+            .unwrap();
+        let params = config.params(number, Some(name));
+        // Parts without known answer are not run at all, they might not even terminate on this input.
+        for part in Part::ALL
+            .into_iter()
+            .filter(|part| expected.get(*part).is_some())
+        {
+            let answer = day.solve_with_params(&input, part, &params).unwrap();
+            if expected.matches(part, &answer) == Some(false) {
+                failures.push(format!(
+                    "{name} part {part}: expected {}, got {answer}",
+                    expected.get(part).unwrap()
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+macro_rules! days {
+    ($($name:ident: $number:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check($number);
+            }
+        )*
+    };
+}
+
+days! {
+    day_01: 1,
+    day_02: 2,
+    day_03: 3,
+    day_04: 4,
+    day_05: 5,
+    day_06: 6,
+    day_07: 7,
+    day_08: 8,
+    day_09: 9,
+    day_10: 10,
+    day_11: 11,
+    day_12: 12,
+    day_13: 13,
+    day_14: 14,
+    day_15: 15,
+    day_16: 16,
+    day_17: 17,
+    day_18: 18,
+    day_19: 19,
+    day_20: 20,
+    day_21: 21,
+    day_22: 22,
+    day_23: 23,
+    day_24: 24,
+    day_25: 25,
+}
@@ -0,0 +1,56 @@
+//! Runs a day on a directory of inputs, some with known answers, one of them wrong, and one which is not an input.
+
+use std::fs;
+
+use common::{
+    batch,
+    config::Config,
+    input::{data_dir, Source},
+    summary::Verdict,
+    Part,
+};
+
+#[test]
+fn batch_reports_every_input() {
+    let days = aoc::days();
+    let day = days.iter().find(|day| day.number == 1).unwrap();
+    let dir = std::env::temp_dir().join(format!("batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let input = Source::File(data_dir(1).join("input.txt")).read(1).unwrap();
+    fs::write(dir.join("alice.txt"), &input).unwrap();
+    fs::write(dir.join("bob.txt"), &input).unwrap();
+    fs::write(dir.join("carol.txt"), "1000\ntwo thousand\n").unwrap();
+    fs::write(dir.join("dave.txt"), "1000\n2000\n\n3000\n").unwrap();
+    fs::write(
+        dir.join("answers.toml"),
+        "[alice]\npart1 = \"69693\"\n\n[bob]\npart1 = \"1\"\n",
+    )
+    .unwrap();
+
+    let summaries = batch::run(day, &dir, &Config::default(), &[Part::One]).unwrap();
+    let verdicts = summaries
+        .iter()
+        .map(|file| (file.file.as_str(), file.summary.parts[0].verdict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        verdicts,
+        [
+            ("alice.txt", Verdict::Pass),
+            ("bob.txt", Verdict::Fail),
+            ("carol.txt", Verdict::Error),
+            ("dave.txt", Verdict::Unknown),
+        ]
+    );
+    assert!(!batch::all_passed(&summaries));
+
+    let mut csv = Vec::new();
+    batch::write_csv(&summaries, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "file,part,verdict,time_ns,answer,expected");
+    assert!(lines[2].starts_with("bob.txt,1,fail,") && lines[2].ends_with(",69693,1"));
+    // The error message spans several lines, so it is quoted
+    assert!(lines[3].starts_with("carol.txt,1,error,"));
+    assert!(lines[3].contains(",\"") && lines[3].contains("carol.txt:2:1"));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,26 @@
+//! Checks that the random inputs of every day are accepted by its parser.
+
+#[test]
+fn generated_inputs_parse() {
+    for day in aoc::days() {
+        for seed in 0..10 {
+            for size in [0, 1, 2, 10, 50] {
+                let input = day.generate(seed, size);
+                if let Err(error) = day.validate(&input) {
+                    panic!(
+                        "Day {:02}, seed {seed}, size {size}:\n{error}\n{input}",
+                        day.number
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_depend_on_the_seed_only() {
+    for day in aoc::days() {
+        assert_eq!(day.generate(1, 20), day.generate(1, 20));
+        assert_ne!(day.generate(1, 20), day.generate(2, 20));
+    }
+}
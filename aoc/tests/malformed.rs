@@ -0,0 +1,127 @@
+//! Inputs which parse line by line, but which the days could not be solved with, are rejected by their parsers.
+
+use common::{Day, ParseError};
+
+fn parse_error(number: u8, input: &str) -> ParseError {
+    aoc::days()
+        .into_iter()
+        .find(|day: &Day| day.number == number)
+        .unwrap()
+        .validate(input)
+        .unwrap_err()
+}
+
+#[test]
+fn day03_needs_items_in_both_compartments_and_groups_with_a_badge() {
+    let error = parse_error(3, "abcd\n");
+    assert_eq!(
+        error.expected,
+        "a rucksack with an item in both compartments"
+    );
+    assert_eq!(error.found, "'abcd'");
+    let error = parse_error(3, "aa\nbb\ncc\n");
+    assert_eq!(
+        error.expected,
+        "a group of three rucksacks with a common item"
+    );
+    let error = parse_error(3, "aa\naa\naa\naa\n");
+    assert_eq!(error.expected, "a group of three rucksacks");
+    assert_eq!(error.line, 4);
+}
+
+#[test]
+fn day05_needs_enough_crates_to_move() {
+    let error = parse_error(
+        5,
+        "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 3 from 1 to 2\n",
+    );
+    assert_eq!(error.expected, "a number of crates from 0 to 2");
+    assert_eq!((error.line, error.column), (6, 6));
+}
+
+#[test]
+fn day06_needs_a_start_of_message_marker() {
+    let error = parse_error(6, "a");
+    assert_eq!(
+        error.expected,
+        "a start-of-message marker of 14 different characters"
+    );
+    assert_eq!(error.found, "end of input");
+    parse_error(6, "abcdabcdabcdabcdabcd\n");
+}
+
+#[test]
+fn day08_needs_a_tree() {
+    let error = parse_error(8, "");
+    assert_eq!(error.expected, "a tree height from 0 to 9");
+}
+
+#[test]
+fn day12_needs_a_start_and_an_end() {
+    assert_eq!(parse_error(12, "a").expected, "the start S");
+    assert_eq!(parse_error(12, "Sab\n").expected, "the end E");
+}
+
+#[test]
+fn day14_needs_a_path_of_rock() {
+    parse_error(14, "");
+    let error = parse_error(14, "498,4\n");
+    assert_eq!(error.expected, "a path of rock from one point to another");
+}
+
+#[test]
+fn day16_needs_the_valve_to_start_at() {
+    let tunnels = "Valve BB has flow rate=3; tunnel leads to valve CC\n\
+        Valve CC has flow rate=0; tunnel leads to valve BB\n";
+    assert_eq!(
+        parse_error(16, tunnels).expected,
+        "the valve AA to start at"
+    );
+}
+
+#[test]
+fn day17_needs_a_jet() {
+    let error = parse_error(17, "\n");
+    assert_eq!(error.expected, "'<' or '>'");
+    assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn day18_needs_a_cube() {
+    assert_eq!(parse_error(18, "").expected, "a cube");
+}
+
+#[test]
+fn day20_needs_two_numbers_and_a_zero() {
+    assert_eq!(parse_error(20, "").expected, "at least two numbers to mix");
+    assert_eq!(parse_error(20, "1").expected, "at least two numbers to mix");
+    assert_eq!(parse_error(20, "1\n2\n").expected, "a 0 among the numbers");
+}
+
+#[test]
+fn day21_needs_the_root_monkey() {
+    assert_eq!(parse_error(21, "").expected, "a monkey named 'root'");
+    let error = parse_error(21, "root: 5\n");
+    assert_eq!(error.expected, "a root monkey with an operation");
+    assert_eq!(error.found, "'root'");
+}
+
+#[test]
+fn day21_needs_the_human_once() {
+    let monkeys = "root: aaaa + bbbb\naaaa: 1\nbbbb: 2\n";
+    assert_eq!(parse_error(21, monkeys).expected, "a monkey named 'humn'");
+    let monkeys = "root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 2\nhumn: 3\n";
+    let error = parse_error(21, monkeys);
+    assert_eq!(
+        error.expected,
+        "a human whose number the root monkey depends on once"
+    );
+    assert_eq!(error.line, 4);
+}
+
+#[test]
+fn day22_needs_the_net_of_a_cube() {
+    let error = parse_error(22, "..\n..\n\n1R1\n");
+    assert_eq!(error.expected, "a map which folds into a cube");
+    assert_eq!((error.line, error.column), (1, 1));
+}
@@ -0,0 +1,111 @@
+//! Parameters which the days can not be solved with are rejected, and the ones they can are used by every way of
+//! solving them.
+
+use common::{
+    checkpoint::Checkpoint,
+    config::{parse_param, Config, Params},
+    input::Source,
+    progress::{Outcome, Progress},
+    Day, Part,
+};
+
+fn day(number: u8) -> Day {
+    aoc::days()
+        .into_iter()
+        .find(|day| day.number == number)
+        .unwrap()
+}
+
+fn params(params: &[&str]) -> Params {
+    params
+        .iter()
+        .map(|param| parse_param(param).unwrap())
+        .collect()
+}
+
+#[test]
+fn rejects_parameters_out_of_range() {
+    for (number, param) in [
+        (7, "disk_size=10"),
+        (9, "knots=0"),
+        (15, "max_coordinate=-5"),
+        (16, "minutes=31"),
+        (19, "blueprints=0"),
+        (19, "max_time=100"),
+        (9, "knots=-1"),
+        (9, "length=2"),
+    ] {
+        let error = day(number).check_params(&params(&[param])).unwrap_err();
+        assert!(
+            error.starts_with(&format!("Invalid parameters of day {number}: ")),
+            "{param}: {error}"
+        );
+    }
+    assert!(day(1).check_params(&params(&["knots=2"])).is_err());
+}
+
+/// The example of the puzzle description of day 7, which has no demo input of its own.
+const FILESYSTEM: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n\
+    2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n\
+    5626152 d.ext\n7214296 k";
+
+#[test]
+fn solves_with_parameters_at_the_limits() {
+    let solve = |number, input: &str, given: &[&str]| {
+        let day = day(number);
+        let params = params(given);
+        day.check_params(&params).unwrap();
+        day.solve_with_params(input, Part::Two, &params).unwrap()
+    };
+    // A rope of a single knot visits the same positions as its head
+    let motions = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+    assert_eq!(solve(9, motions, &["knots=1"]), "20");
+    assert_eq!(solve(7, FILESYSTEM, &[]), "24933642");
+    // Only deleting everything frees enough space
+    let disk = ["disk_size=30000000", "needed_space=30000000"];
+    assert_eq!(solve(7, FILESYSTEM, &disk), "48381165");
+}
+
+#[test]
+fn resumes_and_cancels_with_parameters() {
+    let day = day(15);
+    let input = Source::Demo(1).read(15).unwrap();
+    let params = Config::load().unwrap().params(15, Some("demo_input"));
+    assert!(!params.is_empty());
+
+    let checkpoint = &mut Checkpoint::disabled();
+    let answer = day.solve_resumable(&input, Part::Two, &params, checkpoint);
+    assert_eq!(answer, Some(Ok("56000011".to_owned())));
+    let progress = &mut Progress::ignored();
+    let outcome = day.solve_cancellable(&input, Part::Two, &params, progress);
+    assert_eq!(outcome, Some(Ok(Outcome::Finished("56000011".to_owned()))));
+    let benchmark = day.bench(&input, &[Part::Two], 1, &params).unwrap();
+    assert!(benchmark.part2.is_some());
+}
+
+#[test]
+fn resumes_a_checkpoint_only_with_the_same_parameters() {
+    let day = day(15);
+    let input = Source::Demo(1).read(15).unwrap();
+    let path = std::env::temp_dir().join(format!("params-{}.json", std::process::id()));
+    let interval = std::time::Duration::ZERO;
+    let published = day.effective_params(&params(&[])).unwrap();
+    Checkpoint::create(&path, 15, Part::Two, &input, &published, interval).unwrap();
+
+    // The published values count, whether they are given or not
+    let given = day
+        .effective_params(&params(&["max_coordinate=4000000"]))
+        .unwrap();
+    assert!(Checkpoint::resume(&path, 15, Part::Two, &input, &given, interval).is_ok());
+    let other = day
+        .effective_params(&params(&["max_coordinate=20"]))
+        .unwrap();
+    let error = Checkpoint::resume(&path, 15, Part::Two, &input, &other, interval)
+        .err()
+        .unwrap();
+    assert!(
+        error.to_string().contains("max_coordinate=4000000"),
+        "{error}"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,44 @@
+//! Valid inputs which have no answer are solved with one saying so, instead of failing.
+
+use common::{
+    config::{parse_param, Params},
+    Day, Part,
+};
+
+fn day(number: u8) -> Day {
+    aoc::days()
+        .into_iter()
+        .find(|day| day.number == number)
+        .unwrap()
+}
+
+fn solve(number: u8, input: &str, part: Part) -> String {
+    day(number).solve(input, part).unwrap()
+}
+
+#[test]
+fn day12_without_a_way_up_to_the_end() {
+    for part in Part::ALL {
+        assert_eq!(solve(12, "SaEz\n", part), "no answer");
+    }
+}
+
+#[test]
+fn day24_without_a_way_through_the_valley() {
+    let valley = "#.###\n#.#.#\n###.#\n";
+    for part in Part::ALL {
+        assert_eq!(solve(24, valley, part), "no answer");
+    }
+}
+
+#[test]
+fn day15_with_a_sensor_covering_the_whole_area() {
+    let day = day(15);
+    let report = "Sensor at x=0, y=0: closest beacon is at x=0, y=100\n";
+    let params = [parse_param("max_coordinate=5").unwrap()]
+        .into_iter()
+        .collect::<Params>();
+    day.check_params(&params).unwrap();
+    let answer = day.solve_with_params(report, Part::Two, &params).unwrap();
+    assert_eq!(answer, "no answer");
+}
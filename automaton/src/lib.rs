@@ -0,0 +1,117 @@
+//! An engine for simulations in discrete time: the rules change a state tick by tick, the engine runs them until the
+//! state stops changing, the rules stop, or the states start to repeat.
+
+use std::{collections::HashMap, hash::Hash, iter};
+
+/// What a tick did to the state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tick {
+    /// The state changed.
+    Changed,
+    /// The state stayed as it was, and stays so with every further tick.
+    Unchanged,
+    /// The rules do not apply to the state anymore, like to sand which fell off the map. The state stayed as it was.
+    Stopped,
+}
+
+/// How a state changes from one tick to the next. Everything that changes over time belongs into the state, like the
+/// position in a repeating pattern, so that equal states go on equally.
+pub trait Rules {
+    type State;
+
+    fn tick(&self, state: &mut Self::State) -> Tick;
+}
+
+/// Where the states start to repeat: the state after `start + length` ticks equals the one after `start` ticks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A state together with the rules it follows.
+pub struct Automaton<R: Rules> {
+    rules: R,
+    state: R::State,
+    ticks: usize,
+}
+
+impl<R: Rules> Automaton<R> {
+    pub fn new(rules: R, state: R::State) -> Self {
+        Self {
+            rules,
+            state,
+            ticks: 0,
+        }
+    }
+
+    pub fn state(&self) -> &R::State {
+        &self.state
+    }
+
+    pub fn into_state(self) -> R::State {
+        self.state
+    }
+
+    /// The number of ticks so far, including the ones which did not change the state.
+    pub fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    pub fn tick(&mut self) -> Tick {
+        self.ticks += 1;
+        self.rules.tick(&mut self.state)
+    }
+
+    /// Ticks the given number of times, or until the state stops changing. Returns what the last tick did.
+    pub fn run(&mut self, ticks: usize) -> Tick {
+        let mut tick = Tick::Changed;
+        for _ in 0..ticks {
+            tick = self.tick();
+            if tick != Tick::Changed {
+                break;
+            }
+        }
+        tick
+    }
+
+    /// Ticks until the state stops changing, either because it settled or because the rules stopped. Returns what
+    /// the last tick did.
+    pub fn fixpoint(&mut self) -> Tick {
+        loop {
+            let tick = self.tick();
+            if tick != Tick::Changed {
+                return tick;
+            }
+        }
+    }
+
+    /// Ticks until the state is one seen before, comparing the keys of the states. The key holds what makes up the
+    /// state, or the part of it which decides how it goes on, and all keys are kept until the cycle is found. It is
+    /// taken of every state in order, starting with the current one, so it can also record how the states evolve.
+    /// There is no cycle if the rules stop before.
+    pub fn find_cycle<K: Hash + Eq>(
+        &mut self,
+        mut key: impl FnMut(&R::State) -> K,
+    ) -> Option<Cycle> {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(start) = seen.insert(key(&self.state), self.ticks) {
+                return Some(Cycle {
+                    start,
+                    length: self.ticks - start,
+                });
+            }
+            if self.tick() == Tick::Stopped {
+                return None;
+            }
+        }
+    }
+
+    /// The current state and the state after every tick which changed it, as seen through `view`.
+    pub fn states<T>(mut self, mut view: impl FnMut(&R::State) -> T) -> impl Iterator<Item = T> {
+        let first = view(&self.state);
+        let rest = iter::from_fn(move || (self.tick() == Tick::Changed).then(|| view(&self.state)));
+        iter::once(first).chain(rest.fuse())
+    }
+}
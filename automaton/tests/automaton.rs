@@ -0,0 +1,100 @@
+//! Running small rules whose fixpoints and cycles are known.
+
+use automaton::{Automaton, Cycle, Rules, Tick};
+
+/// Counts up to the limit and stays there.
+struct Saturating {
+    limit: u32,
+}
+
+impl Rules for Saturating {
+    type State = u32;
+
+    fn tick(&self, count: &mut u32) -> Tick {
+        match *count < self.limit {
+            true => {
+                *count += 1;
+                Tick::Changed
+            }
+            false => Tick::Unchanged,
+        }
+    }
+}
+
+/// Goes on with `n * n % modulus`, which runs into a cycle after a few steps.
+struct Squaring {
+    modulus: u64,
+}
+
+impl Rules for Squaring {
+    type State = u64;
+
+    fn tick(&self, n: &mut u64) -> Tick {
+        *n = *n * *n % self.modulus;
+        Tick::Changed
+    }
+}
+
+/// Counts down, and stops below zero.
+struct Countdown;
+
+impl Rules for Countdown {
+    type State = i32;
+
+    fn tick(&self, n: &mut i32) -> Tick {
+        match *n > 0 {
+            true => {
+                *n -= 1;
+                Tick::Changed
+            }
+            false => Tick::Stopped,
+        }
+    }
+}
+
+#[test]
+fn runs_to_the_fixpoint() {
+    let mut automaton = Automaton::new(Saturating { limit: 5 }, 0);
+    assert_eq!(automaton.run(3), Tick::Changed);
+    assert_eq!(*automaton.state(), 3);
+    assert_eq!(automaton.fixpoint(), Tick::Unchanged);
+    assert_eq!(*automaton.state(), 5);
+    // The tick which did not change the state counts as well
+    assert_eq!(automaton.ticks(), 6);
+    assert_eq!(automaton.run(10), Tick::Unchanged);
+}
+
+#[test]
+fn states_end_with_the_last_change() {
+    let states = Automaton::new(Saturating { limit: 3 }, 0).states(|count| *count);
+    assert_eq!(states.collect::<Vec<_>>(), [0, 1, 2, 3]);
+    let states = Automaton::new(Countdown, 2).states(|n| *n);
+    assert_eq!(states.collect::<Vec<_>>(), [2, 1, 0]);
+}
+
+#[test]
+fn finds_where_the_states_repeat() {
+    // 3, 9, 81, 61, 21, 41, 81, ...
+    let mut states = vec![];
+    let mut automaton = Automaton::new(Squaring { modulus: 100 }, 3);
+    let cycle = automaton.find_cycle(|n| {
+        states.push(*n);
+        *n
+    });
+    assert_eq!(
+        cycle,
+        Some(Cycle {
+            start: 2,
+            length: 4
+        })
+    );
+    assert_eq!(states, [3, 9, 81, 61, 21, 41, 81]);
+    assert_eq!(automaton.ticks(), 6);
+}
+
+#[test]
+fn finds_no_cycle_when_the_rules_stop() {
+    let mut automaton = Automaton::new(Countdown, 3);
+    assert_eq!(automaton.find_cycle(|n| *n), None);
+    assert_eq!(*automaton.state(), 0);
+}
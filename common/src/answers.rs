@@ -0,0 +1,80 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::{input::data_dir, Part};
+
+/// The expected answers of one input. Parts whose answer is not known are left out.
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Whether `answer` is the expected one. Trailing whitespace is ignored, so multi-line answers can be written as
+    /// TOML multi-line strings.
+    pub fn matches(&self, part: Part, answer: &str) -> Option<bool> {
+        self.get(part)
+            .map(|expected| expected.trim_end() == answer.trim_end())
+    }
+}
+
+/// The known answers of a day, stored in its `data/answers.toml`. Every table is named after an input file of the
+/// data directory, without the `.txt` extension:
+///
+/// ```toml
+/// [demo_input]
+/// part1 = "152"
+/// part2 = "301"
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, Expected>);
+
+impl Answers {
+    /// Loads the answers of the given day. A day without manifest has no known answers.
+    pub fn load(day: u8) -> io::Result<Self> {
+        Self::read(&data_dir(day).join("answers.toml"))
+    }
+
+    /// Loads the answers of the manifest at the path. If there is none, no answers are known.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let manifest = match fs::read_to_string(path) {
+            Ok(manifest) => manifest,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => {
+                return Err(io::Error::new(
+                    error.kind(),
+                    format!("{}: {error}", path.display()),
+                ))
+            }
+        };
+        toml::from_str(&manifest).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {error}", path.display()),
+            )
+        })
+    }
+
+    /// The expected answers of the input file with the given name.
+    pub fn get(&self, name: &str) -> Option<&Expected> {
+        self.0.get(name)
+    }
+
+    /// The names of the input files with their expected answers.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Expected)> {
+        self.0
+            .iter()
+            .map(|(name, expected)| (name.as_str(), expected))
+    }
+}
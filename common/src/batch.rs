@@ -0,0 +1,146 @@
+//! Running one day on every input file of a directory, to find the inputs a solution does not work on. The directory
+//! may have an `answers.toml` like the data directories, to compare the answers with the known ones.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    answers::Answers,
+    config::Config,
+    input::Source,
+    summary::{self, DaySummary},
+    Day, Part,
+};
+
+/// How the results of a batch are printed.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A table for reading, like the one of the run-all mode.
+    #[default]
+    Table,
+    /// One line per part, to be processed further.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format '{s}', expected table or csv")),
+        }
+    }
+}
+
+/// The outcome of all selected parts on one input file.
+#[derive(Clone, Debug, Serialize)]
+pub struct FileSummary {
+    pub file: String,
+    #[serde(flatten)]
+    pub summary: DaySummary,
+}
+
+/// The input files of the directory, sorted by name. Hidden files and the answers are left out.
+fn inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let in_dir =
+        |error: io::Error| io::Error::new(error.kind(), format!("{}: {error}", dir.display()));
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(dir).map_err(in_dir)? {
+        let entry = entry.map_err(in_dir)?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if entry.file_type().map_err(in_dir)?.is_file()
+            && !name.starts_with('.')
+            && name != "answers.toml"
+        {
+            inputs.push(entry.path());
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Runs the day on every input file of the directory in parallel, with the parameters of `config` for files of the same
+/// name. The summaries are in the order of the file names.
+pub fn run(day: &Day, dir: &Path, config: &Config, parts: &[Part]) -> io::Result<Vec<FileSummary>> {
+    let answers = Answers::read(&dir.join("answers.toml"))?;
+    let summaries = inputs(dir)?
+        .into_par_iter()
+        .map(|path| FileSummary {
+            file: path.file_name().unwrap().to_string_lossy().into_owned(),
+            summary: DaySummary::run_with(day, &Source::File(path), &answers, config, parts),
+        })
+        .collect();
+    Ok(summaries)
+}
+
+/// Prints one row per part of every file and a line with the totals.
+pub fn print_table(summaries: &[FileSummary], wall_time: Duration) {
+    let width = summaries
+        .iter()
+        .map(|file| file.file.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("File".len());
+    println!("{:<width$} Part Result         Time  Answer", "File");
+    for file in summaries {
+        if let Some(reason) = &file.summary.skipped {
+            println!("{:<width$}      skipped              {reason}", file.file);
+        }
+        for part in &file.summary.parts {
+            println!("{:<width$} {part}", file.file);
+        }
+    }
+    summary::print_totals(
+        summaries.iter().map(|file| &file.summary),
+        "files",
+        wall_time,
+    );
+}
+
+/// Quotes the field if it contains a separator, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_owned(),
+    }
+}
+
+/// Writes one line per part of every file. A file which could not be read has a single line without part, with the
+/// reason as its answer.
+pub fn write_csv(summaries: &[FileSummary], mut out: impl Write) -> io::Result<()> {
+    writeln!(out, "file,part,verdict,time_ns,answer,expected")?;
+    for file in summaries {
+        let name = csv_field(&file.file);
+        if let Some(reason) = &file.summary.skipped {
+            writeln!(out, "{name},,skipped,,{},", csv_field(reason))?;
+        }
+        for part in &file.summary.parts {
+            writeln!(
+                out,
+                "{name},{},{},{},{},{}",
+                part.part,
+                part.verdict.name(),
+                part.time.as_nanos(),
+                csv_field(&part.answer),
+                csv_field(part.expected.as_deref().unwrap_or_default()),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether no answer was wrong and no file failed to parse.
+pub fn all_passed(summaries: &[FileSummary]) -> bool {
+    summary::all_passed(summaries.iter().map(|file| &file.summary))
+}
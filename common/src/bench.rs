@@ -0,0 +1,128 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{config::Configurable, ParseError, Part, Solution};
+
+pub(crate) fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// The spread of the durations of repeated runs of the same phase.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Timings {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Runs `f` the given number of times. Dropping the result is not part of the measurement.
+    fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> Self {
+        let mut durations = (0..runs.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                let result = black_box(f());
+                let duration = start.elapsed();
+                drop(result);
+                duration
+            })
+            .collect::<Vec<_>>();
+        durations.sort();
+        Self {
+            min: durations[0],
+            median: durations[durations.len() / 2],
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>12.3?} {:>12.3?} {:>12.3?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// The timings of the phases of a day. Parts that were not selected are left out.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub runs: u32,
+    pub parse: Timings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Timings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Timings>,
+}
+
+impl Benchmark {
+    /// Measures parsing and the given parts, each on its own. The parts all work on the same parsed input.
+    pub(crate) fn run<S: Solution>(
+        input: &str,
+        parts: &[Part],
+        runs: u32,
+    ) -> Result<Self, ParseError> {
+        let parsed = S::parse(input)?;
+        let part1 = || S::part1(&parsed);
+        let part2 = || S::part2(&parsed);
+        Ok(Self::measure::<S>(input, parts, runs, part1, part2))
+    }
+
+    /// Like [`Benchmark::run`], with the parts solved with the parameters, which were checked before.
+    pub(crate) fn run_with<S: Configurable>(
+        input: &str,
+        parts: &[Part],
+        runs: u32,
+        params: &S::Params,
+    ) -> Result<Self, ParseError> {
+        let parsed = S::parse(input)?;
+        let part1 = || S::part1_with(&parsed, params);
+        let part2 = || S::part2_with(&parsed, params);
+        Ok(Self::measure::<S>(input, parts, runs, part1, part2))
+    }
+
+    fn measure<S: Solution>(
+        input: &str,
+        parts: &[Part],
+        runs: u32,
+        part1: impl FnMut() -> S::Answer1,
+        part2: impl FnMut() -> S::Answer2,
+    ) -> Self {
+        let parse = Timings::measure(runs, || S::parse(input));
+        let part1 = parts
+            .contains(&Part::One)
+            .then(|| Timings::measure(runs, part1));
+        let part2 = parts
+            .contains(&Part::Two)
+            .then(|| Timings::measure(runs, part2));
+        Self {
+            day: S::DAY,
+            runs,
+            parse,
+            part1,
+            part2,
+        }
+    }
+
+    /// The measured phases with their names.
+    pub fn phases(&self) -> impl Iterator<Item = (&'static str, Timings)> {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(name, timings)| timings.map(|timings| (name, timings)))
+    }
+}
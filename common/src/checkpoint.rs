@@ -0,0 +1,273 @@
+//! Saving the progress of long searches to a file from time to time, so that they can continue from there after they
+//! were interrupted.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    config::{Configurable, Params},
+    Part,
+};
+
+/// How often the progress is written to the file at most, if not given explicitly.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Searches report their progress at every step, but the clock is only looked at every so many updates.
+const UPDATES_PER_CHECK: u32 = 1024;
+
+/// The content of a checkpoint file.
+#[derive(Default, Serialize, Deserialize)]
+struct Saved {
+    day: u8,
+    part: u8,
+    /// A hash of the input, so that a checkpoint is not resumed with another input.
+    input: u64,
+    /// The parameters of the searches, including the published values of the ones which were not given, so that a
+    /// checkpoint is not resumed with other ones either.
+    params: Params,
+    /// The progress of every search, by name.
+    searches: BTreeMap<String, serde_json::Value>,
+}
+
+/// FNV-1a, which unlike the hasher of the standard library gives the same hash in every build.
+fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Where the searches of a part save their progress. Every search has a name, which has to be unique within the part,
+/// and saves whatever it needs to continue.
+pub struct Checkpoint {
+    /// The file to save to. Without one, nothing is saved and nothing is resumed.
+    path: Option<PathBuf>,
+    saved: Saved,
+    interval: Duration,
+    saved_at: Instant,
+    updates: u32,
+    /// The first error while saving. The searches go on without saving anything after it.
+    error: Option<io::Error>,
+}
+
+impl Checkpoint {
+    /// A checkpoint which never saves anything, for solving without one.
+    pub fn disabled() -> Self {
+        Self {
+            path: None,
+            saved: Saved::default(),
+            interval: DEFAULT_INTERVAL,
+            saved_at: Instant::now(),
+            updates: 0,
+            error: None,
+        }
+    }
+
+    /// Starts a new checkpoint of the part, replacing the file if there is one already. The parameters are the ones
+    /// the part is solved with, see [`Day::effective_params`](crate::Day::effective_params).
+    pub fn create(
+        path: &Path,
+        day: u8,
+        part: Part,
+        input: &str,
+        params: &Params,
+        interval: Duration,
+    ) -> io::Result<Self> {
+        let saved = Saved {
+            day,
+            part: part_number(part),
+            input: hash(input),
+            params: params.clone(),
+            searches: BTreeMap::new(),
+        };
+        let mut checkpoint = Self {
+            path: Some(path.to_owned()),
+            saved,
+            interval,
+            ..Self::disabled()
+        };
+        checkpoint.write()?;
+        Ok(checkpoint)
+    }
+
+    /// Continues the checkpoint in the file, which has to be one of the same part with the same input and parameters.
+    pub fn resume(
+        path: &Path,
+        day: u8,
+        part: Part,
+        input: &str,
+        params: &Params,
+        interval: Duration,
+    ) -> io::Result<Self> {
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {message}", path.display()),
+            )
+        };
+        let content = fs::read_to_string(path).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+        })?;
+        let saved: Saved =
+            serde_json::from_str(&content).map_err(|error| invalid(error.to_string()))?;
+        if (saved.day, saved.part) != (day, part_number(part)) {
+            return Err(invalid(format!(
+                "a checkpoint of day {} part {}, not of day {day} part {part}",
+                saved.day, saved.part
+            )));
+        }
+        if saved.input != hash(input) {
+            return Err(invalid("a checkpoint of another input".to_owned()));
+        }
+        if saved.params != *params {
+            return Err(invalid(format!(
+                "a checkpoint with the parameters {}, not {}",
+                listed(&saved.params),
+                listed(params)
+            )));
+        }
+        Ok(Self {
+            path: Some(path.to_owned()),
+            saved,
+            interval,
+            ..Self::disabled()
+        })
+    }
+
+    /// The progress the search saved before, if there is any. A progress which does not fit `T` is ignored, so the
+    /// search starts over.
+    pub fn progress<T: DeserializeOwned>(&self, search: &str) -> Option<T> {
+        let progress = self.saved.searches.get(search)?;
+        T::deserialize(progress).ok()
+    }
+
+    /// Saves the progress of the search, if the last save was long enough ago. `progress` is only called then.
+    pub fn update<T: Serialize>(&mut self, search: &str, progress: impl FnOnce() -> T) {
+        self.updates += 1;
+        if self.updates < UPDATES_PER_CHECK {
+            return;
+        }
+        self.updates = 0;
+        if self.saved_at.elapsed() >= self.interval {
+            self.save(search, &progress());
+        }
+    }
+
+    /// Keeps the progress of the search, like when it is done, and saves it with the next write to the file. The file
+    /// is written right away if the last save was long enough ago.
+    pub fn save<T: Serialize>(&mut self, search: &str, progress: &T) {
+        if self.path.is_none() || self.error.is_some() {
+            return;
+        }
+        let result = serde_json::to_value(progress)
+            .map_err(io::Error::other)
+            .and_then(|progress| {
+                self.saved.searches.insert(search.to_owned(), progress);
+                match self.saved_at.elapsed() >= self.interval {
+                    true => self.write(),
+                    false => Ok(()),
+                }
+            });
+        self.error = result.err();
+    }
+
+    /// The part of the checkpoint belonging to the search with the name, for searches which are handed their own.
+    pub fn search<'a>(&'a mut self, name: &'a str) -> SearchCheckpoint<'a> {
+        SearchCheckpoint {
+            checkpoint: self,
+            name,
+        }
+    }
+
+    /// Removes the file once the part is solved, or returns the error which stopped the saving.
+    pub fn finish(self) -> io::Result<()> {
+        let Some(path) = self.path else {
+            return Ok(());
+        };
+        match self.error {
+            Some(error) => Err(io::Error::new(
+                error.kind(),
+                format!("{}: {error}", path.display()),
+            )),
+            None => fs::remove_file(&path).map_err(|error| {
+                io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+            }),
+        }
+    }
+
+    /// Writes the whole checkpoint to a temporary file first, so an interruption never leaves half a file behind.
+    fn write(&mut self) -> io::Result<()> {
+        self.saved_at = Instant::now();
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(".tmp");
+        fs::write(&temporary, serde_json::to_string(&self.saved)?)?;
+        fs::rename(&temporary, path)
+    }
+}
+
+/// The progress of a single search in a checkpoint, see [`Checkpoint::search`].
+pub struct SearchCheckpoint<'a> {
+    checkpoint: &'a mut Checkpoint,
+    name: &'a str,
+}
+
+impl SearchCheckpoint<'_> {
+    /// See [`Checkpoint::progress`].
+    pub fn progress<T: DeserializeOwned>(&self) -> Option<T> {
+        self.checkpoint.progress(self.name)
+    }
+
+    /// See [`Checkpoint::update`].
+    pub fn update<T: Serialize>(&mut self, progress: impl FnOnce() -> T) {
+        self.checkpoint.update(self.name, progress)
+    }
+
+    /// See [`Checkpoint::save`].
+    pub fn save<T: Serialize>(&mut self, progress: &T) {
+        self.checkpoint.save(self.name, progress)
+    }
+}
+
+/// The parameters like on the command line, or "none".
+fn listed(params: &Params) -> String {
+    match params.is_empty() {
+        true => "none".to_owned(),
+        false => params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// A puzzle with long searches, which saves their progress to a checkpoint and continues from the saved one.
+pub trait Resumable: Configurable {
+    /// Solves part 1 like [`Configurable::part1_with`], continuing from the progress saved in the checkpoint.
+    fn part1_resumable(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer1;
+
+    /// Solves part 2 like [`Configurable::part2_with`], continuing from the progress saved in the checkpoint.
+    fn part2_resumable(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer2;
+}
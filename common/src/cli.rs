@@ -0,0 +1,891 @@
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    iter::Peekable,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    batch::{self, Format},
+    bench::Benchmark,
+    checkpoint::{Checkpoint, DEFAULT_INTERVAL},
+    config::{self, Config, Params},
+    input::Source,
+    journal::{Journal, Verdict},
+    progress::{CancellationToken, Outcome, Progress},
+    render::{self, Rgb, Selection},
+    summary, visualize, Day, ParseError, Part,
+};
+
+const USAGE: &str = "[--day N] [--part 1|2] [--input PATH|- | --demo [N]] [--config PATH] [--param NAME=VALUE]... \
+     [--bench [RUNS] | --all | --stats | --visualize \
+     | --export-frames PATH [--palette COLORS] [--every N] [--max-frames N] \
+     | --checkpoint PATH [--resume] | --timeout SECS] [--report PATH] \
+     | --day N --batch DIR [--part 1|2] [--config PATH] [--param NAME=VALUE]... [--format table|csv] [--report PATH] \
+     | --day N --part 1|2 --record ANSWER correct|too-high|too-low|wrong \
+     | --day N --generate SIZE [--seed N] [--output PATH]";
+
+/// The number of runs per phase when benchmarking, if not given explicitly.
+const DEFAULT_RUNS: u32 = 10;
+
+/// The number of characters of the progress bar between its brackets.
+const PROGRESS_BAR_WIDTH: usize = 30;
+
+#[derive(Default, Debug, PartialEq)]
+pub struct Options {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub source: Source,
+    /// Benchmark the selected days with the given number of runs per phase, instead of printing the answers.
+    pub bench: Option<u32>,
+    /// Run the selected days in parallel and print a table comparing the answers with the known ones.
+    pub all: bool,
+    /// Print how many states the searches of the selected days went through, together with the answers.
+    pub stats: bool,
+    /// Watch the simulation of the selected day and part in the terminal, instead of printing the answer.
+    pub visualize: bool,
+    /// Where to write pictures of the selected day and part, instead of printing the answer: an animated GIF if the
+    /// path ends with `.gif`, otherwise a directory of PNG files.
+    pub export_frames: Option<PathBuf>,
+    /// The colors of the exported pictures, instead of the ones of the day.
+    pub palette: Option<Vec<Rgb>>,
+    /// Export only every so many pictures, instead of all of them.
+    pub every: Option<usize>,
+    /// Fail instead of exporting more frames than this, [`render::DEFAULT_MAX_FRAMES`] if not given.
+    pub max_frames: Option<usize>,
+    /// Where the selected day and part save the progress of their searches, to continue them after an interruption.
+    pub checkpoint: Option<PathBuf>,
+    /// Continue from the progress saved in the checkpoint, instead of starting over.
+    pub resume: bool,
+    /// Stop the parts which can be stopped early after this time, with the best answer found until then.
+    pub timeout: Option<Duration>,
+    /// Run the selected day on every input file of the directory and print a table of the answers, instead of solving a
+    /// single input.
+    pub batch: Option<PathBuf>,
+    /// How to print the results of the batch, a table if not given.
+    pub format: Option<Format>,
+    /// Add the answer with what the website said about it to the journal of the selected day and part, instead of
+    /// solving.
+    pub record: Option<(String, Verdict)>,
+    /// The configuration with the parameters of the days, instead of `aoc.toml` in the workspace.
+    pub config: Option<PathBuf>,
+    /// Parameters of the selected day, taking precedence over the ones in the configuration.
+    pub params: Vec<(String, toml::Value)>,
+    /// Where to write the benchmark results, the run-all summary or the batch results as JSON.
+    pub report: Option<PathBuf>,
+    /// Write a random input of the given size, instead of solving.
+    pub generate: Option<usize>,
+    /// The seed of the generated input, 0 if not given.
+    pub seed: Option<u64>,
+    /// Where to write the generated input, instead of the standard output.
+    pub output: Option<PathBuf>,
+}
+
+fn value(args: &mut Peekable<impl Iterator<Item = String>>, arg: &str) -> Result<String, String> {
+    args.next().ok_or(format!("Missing value for '{arg}'"))
+}
+
+impl Options {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+        let mut options = Options::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => {
+                    let day = value(&mut args, &arg)?;
+                    options.day = Some(day.parse().map_err(|_| format!("Invalid day '{day}'"))?);
+                }
+                "--part" | "-p" => options.part = Some(value(&mut args, &arg)?.parse()?),
+                "--input" | "-i" => {
+                    options.source = match value(&mut args, &arg)?.as_str() {
+                        "-" => Source::Stdin,
+                        path => Source::File(PathBuf::from(path)),
+                    }
+                }
+                "--demo" => {
+                    let number = args.next_if(|number| number.parse::<u8>().is_ok());
+                    options.source =
+                        Source::Demo(number.map_or(1, |number| number.parse().unwrap()));
+                }
+                "--bench" => {
+                    let runs = args.next_if(|runs| runs.parse::<u32>().is_ok_and(|runs| runs > 0));
+                    options.bench = Some(runs.map_or(DEFAULT_RUNS, |runs| runs.parse().unwrap()));
+                }
+                "--all" | "-a" => options.all = true,
+                "--stats" => options.stats = true,
+                "--visualize" => options.visualize = true,
+                "--export-frames" => {
+                    options.export_frames = Some(PathBuf::from(value(&mut args, &arg)?))
+                }
+                "--palette" => {
+                    let palette = value(&mut args, &arg)?
+                        .split(',')
+                        .map(render::parse_color)
+                        .collect::<Result<_, _>>()?;
+                    options.palette = Some(palette);
+                }
+                "--every" => {
+                    let step = value(&mut args, &arg)?;
+                    let every = step.parse().ok().filter(|every| *every > 0);
+                    options.every = Some(every.ok_or(format!("Invalid step '{step}'"))?);
+                }
+                "--max-frames" => {
+                    let frames = value(&mut args, &arg)?;
+                    let max_frames = frames.parse().ok().filter(|frames| *frames > 0);
+                    options.max_frames =
+                        Some(max_frames.ok_or(format!("Invalid number of frames '{frames}'"))?);
+                }
+                "--checkpoint" => options.checkpoint = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--resume" => options.resume = true,
+                "--timeout" => {
+                    let timeout = value(&mut args, &arg)?;
+                    let seconds = timeout
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+                        .ok_or(format!("Invalid timeout '{timeout}'"))?;
+                    options.timeout = Some(Duration::from_secs_f64(seconds));
+                }
+                "--batch" => options.batch = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--format" => options.format = Some(value(&mut args, &arg)?.parse()?),
+                "--record" => {
+                    let answer = value(&mut args, &arg)?;
+                    options.record = Some((answer, value(&mut args, &arg)?.parse()?));
+                }
+                "--config" => options.config = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--param" => options
+                    .params
+                    .push(config::parse_param(&value(&mut args, &arg)?)?),
+                "--report" => options.report = Some(PathBuf::from(value(&mut args, &arg)?)),
+                "--generate" => {
+                    let size = value(&mut args, &arg)?;
+                    options.generate =
+                        Some(size.parse().map_err(|_| format!("Invalid size '{size}'"))?);
+                }
+                "--seed" => {
+                    let seed = value(&mut args, &arg)?;
+                    options.seed =
+                        Some(seed.parse().map_err(|_| format!("Invalid seed '{seed}'"))?);
+                }
+                "--output" | "-o" => options.output = Some(PathBuf::from(value(&mut args, &arg)?)),
+                _ => return Err(format!("Unknown argument '{arg}'")),
+            }
+        }
+        if options.generate.is_some() {
+            if options.day.is_none() {
+                return Err("'--generate' needs a '--day'".to_owned());
+            }
+            if options.part.is_some()
+                || options.source != Source::default()
+                || options.bench.is_some()
+                || options.all
+                || options.stats
+                || options.visualize
+                || options.export_frames.is_some()
+                || options.checkpoint.is_some()
+                || options.timeout.is_some()
+                || options.batch.is_some()
+                || options.config.is_some()
+                || !options.params.is_empty()
+                || options.report.is_some()
+            {
+                return Err(
+                    "'--generate' can only be combined with '--seed' and '--output'".to_owned(),
+                );
+            }
+        } else if options.seed.is_some() || options.output.is_some() {
+            return Err(
+                "'--seed' and '--output' can only be used together with '--generate'".to_owned(),
+            );
+        }
+        if options.all && options.bench.is_some() {
+            return Err("'--all' and '--bench' can not be used together".to_owned());
+        }
+        if options.visualize {
+            if options.day.is_none() {
+                return Err("'--visualize' needs a '--day'".to_owned());
+            }
+            if options.bench.is_some() || options.all || options.report.is_some() {
+                return Err(
+                    "'--visualize' can not be combined with '--bench', '--all' or '--report'"
+                        .to_owned(),
+                );
+            }
+        }
+        if options.export_frames.is_some() {
+            if options.day.is_none() {
+                return Err("'--export-frames' needs a '--day'".to_owned());
+            }
+            if options.bench.is_some()
+                || options.all
+                || options.visualize
+                || options.report.is_some()
+            {
+                return Err(
+                    "'--export-frames' can not be combined with '--bench', '--all', '--visualize' or '--report'"
+                        .to_owned(),
+                );
+            }
+        } else if options.palette.is_some()
+            || options.every.is_some()
+            || options.max_frames.is_some()
+        {
+            return Err(
+                "'--palette', '--every' and '--max-frames' can only be used together with '--export-frames'"
+                    .to_owned(),
+            );
+        }
+        if options.stats
+            && (options.bench.is_some()
+                || options.all
+                || options.visualize
+                || options.export_frames.is_some())
+        {
+            return Err(
+                "'--stats' can not be combined with '--bench', '--all', '--visualize' or '--export-frames'"
+                    .to_owned(),
+            );
+        }
+        if options.checkpoint.is_some() {
+            if options.day.is_none() || options.part.is_none() {
+                return Err("'--checkpoint' needs a '--day' and a '--part'".to_owned());
+            }
+            if options.bench.is_some()
+                || options.all
+                || options.stats
+                || options.visualize
+                || options.export_frames.is_some()
+            {
+                return Err(
+                    "'--checkpoint' can not be combined with '--bench', '--all', '--stats', '--visualize' or '--export-frames'"
+                        .to_owned(),
+                );
+            }
+        } else if options.resume {
+            return Err("'--resume' can only be used together with '--checkpoint'".to_owned());
+        }
+        if options.timeout.is_some()
+            && (options.bench.is_some()
+                || options.all
+                || options.stats
+                || options.visualize
+                || options.export_frames.is_some()
+                || options.checkpoint.is_some())
+        {
+            return Err(
+                "'--timeout' can not be combined with '--bench', '--all', '--stats', '--visualize', '--export-frames' or '--checkpoint'"
+                    .to_owned(),
+            );
+        }
+        if options.batch.is_some() {
+            if options.day.is_none() {
+                return Err("'--batch' needs a '--day'".to_owned());
+            }
+            if options.source != Source::default()
+                || options.bench.is_some()
+                || options.all
+                || options.stats
+                || options.visualize
+                || options.export_frames.is_some()
+                || options.checkpoint.is_some()
+                || options.timeout.is_some()
+            {
+                return Err(
+                    "'--batch' can only be combined with '--part', '--format' and '--report'"
+                        .to_owned(),
+                );
+            }
+        } else if options.format.is_some() {
+            return Err("'--format' can only be used together with '--batch'".to_owned());
+        }
+        if options.record.is_some() {
+            if options.day.is_none() || options.part.is_none() {
+                return Err("'--record' needs a '--day' and a '--part'".to_owned());
+            }
+            let only_day_and_part = Options {
+                day: options.day,
+                part: options.part,
+                record: options.record.clone(),
+                ..Options::default()
+            };
+            if options != only_day_and_part {
+                return Err("'--record' can only be combined with '--day' and '--part'".to_owned());
+            }
+        }
+        if !options.params.is_empty() {
+            if options.day.is_none() {
+                return Err("'--param' needs a '--day'".to_owned());
+            }
+            if options.stats || options.visualize || options.export_frames.is_some() {
+                return Err(
+                    "'--param' can not be combined with '--stats', '--visualize' or '--export-frames'"
+                        .to_owned(),
+                );
+            }
+        }
+        if options.report.is_some()
+            && options.bench.is_none()
+            && !options.all
+            && options.batch.is_none()
+        {
+            return Err(
+                "'--report' can only be used together with '--bench', '--all' or '--batch'"
+                    .to_owned(),
+            );
+        }
+        Ok(options)
+    }
+}
+
+fn print_answer(day: &Day, part: Part, answer: &str) {
+    if answer.contains('\n') {
+        println!("Day {:02} part {part}:\n{answer}", day.number);
+    } else {
+        println!("Day {:02} part {part}: {answer}", day.number);
+    }
+}
+
+/// The progress bar of a part, with the percentage of its work done.
+fn progress_bar(day: &Day, part: Part, done: f64) -> String {
+    let done = done.clamp(0.0, 1.0);
+    let filled = (done * PROGRESS_BAR_WIDTH as f64) as usize;
+    format!(
+        "Day {:02} part {part} [{}{}] {:>3}%",
+        day.number,
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled),
+        (done * 100.0) as u32
+    )
+}
+
+/// The parameters of the day for the input of the source, see [`Config::params`].
+fn params(config: &Config, day: &Day, source: &Source) -> Params {
+    let path = source.path(day.number);
+    let name = path
+        .as_deref()
+        .and_then(Path::file_stem)
+        .map(|name| name.to_string_lossy());
+    config.params(day.number, name.as_deref())
+}
+
+/// Solves the part with the parameters with a progress bar, if the standard error is a terminal, and stops it after the
+/// timeout. Returns the answer if the part was finished.
+fn solve_with_progress(
+    day: &Day,
+    part: Part,
+    input: &str,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<Option<String>, ParseError> {
+    let show = io::stderr().is_terminal();
+    let start = Instant::now();
+    let token = CancellationToken::new();
+    let outcome = thread::scope(|scope| {
+        // Dropping the sender wakes the timer up when the part is done before the timeout
+        let (done, timer) = mpsc::channel::<()>();
+        if let Some(timeout) = timeout {
+            let token = token.clone();
+            scope.spawn(move || {
+                if timer.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+                    token.cancel();
+                }
+            });
+        }
+        let mut progress = Progress::new(token, |fraction| {
+            if show {
+                eprint!("\r{}", progress_bar(day, part, fraction));
+            }
+        });
+        let outcome = day
+            .solve_cancellable(input, part, params, &mut progress)
+            .unwrap();
+        drop(done);
+        outcome
+    });
+    if show {
+        eprint!("\r{}\r", " ".repeat(progress_bar(day, part, 0.0).len()));
+    }
+    match outcome? {
+        Outcome::Finished(answer) => {
+            print_answer(day, part, &answer);
+            Ok(Some(answer))
+        }
+        Outcome::Cancelled(Some(answer)) => {
+            let stopped = format!("(the best one when stopped after {:.1?})", start.elapsed());
+            print_answer(day, part, &format!("{answer} {stopped}"));
+            Ok(None)
+        }
+        Outcome::Cancelled(None) => {
+            eprintln!(
+                "Day {:02} part {part}: no answer when stopped after {:.1?}",
+                day.number,
+                start.elapsed()
+            );
+            Ok(None)
+        }
+    }
+}
+
+fn print_benchmark(benchmark: &Benchmark) {
+    for (phase, timings) in benchmark.phases() {
+        println!("{:>3} {phase:<6} {timings}", benchmark.day);
+    }
+}
+
+fn write_report(path: &Path, report: &impl Serialize) -> Result<(), String> {
+    let report = serde_json::to_string_pretty(report).map_err(|error| error.to_string())?;
+    fs::write(path, report + "\n").map_err(|error| format!("{}: {error}", path.display()))
+}
+
+fn visualize(day: &Day, part: Part, source: &Source, params: &Params) -> ExitCode {
+    // The simulations only run with the published constants
+    if !params.is_empty() {
+        eprintln!("Day {} can not be visualized with parameters", day.number);
+        return ExitCode::FAILURE;
+    }
+    let input = match source.read(day.number) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let frames = match day.frames(&input, part) {
+        Some(Ok(frames)) => frames,
+        Some(Err(error)) => {
+            match source.path(day.number) {
+                Some(path) => eprintln!("{}", error.in_file(&path)),
+                None => eprintln!("{error}"),
+            }
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("Day {} can not be visualized", day.number);
+            return ExitCode::FAILURE;
+        }
+    };
+    match visualize::play(frames, format!("Day {:02} part {part}", day.number)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn export(
+    day: &Day,
+    part: Part,
+    source: &Source,
+    params: &Params,
+    palette: Option<&[Rgb]>,
+    selection: Selection,
+    path: &Path,
+) -> ExitCode {
+    let Some(default_palette) = day.palette() else {
+        eprintln!("Day {} has no pictures to export", day.number);
+        return ExitCode::FAILURE;
+    };
+    if !params.is_empty() {
+        eprintln!("Day {} can not be exported with parameters", day.number);
+        return ExitCode::FAILURE;
+    }
+    let palette = palette.unwrap_or(&default_palette);
+    if palette.len() != default_palette.len() {
+        eprintln!(
+            "Day {} needs a palette of {} colors, not {}",
+            day.number,
+            default_palette.len(),
+            palette.len()
+        );
+        return ExitCode::from(2);
+    }
+    let input = match source.read(day.number) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match day.export(&input, part, palette, selection, path).unwrap() {
+        Ok(Ok(frames)) => {
+            println!("Wrote {frames} frames to {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Err(error)) => {
+            eprintln!("{}: {error}", path.display());
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            match source.path(day.number) {
+                Some(path) => eprintln!("{}", error.in_file(&path)),
+                None => eprintln!("{error}"),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn checkpointed(
+    day: &Day,
+    part: Part,
+    source: &Source,
+    params: &Params,
+    path: &Path,
+    resume: bool,
+) -> ExitCode {
+    if !day.is_resumable() {
+        eprintln!("Day {} has no searches to save", day.number);
+        return ExitCode::FAILURE;
+    }
+    let effective = match day.effective_params(params) {
+        Ok(effective) => effective,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match source.read(day.number) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (number, interval) = (day.number, DEFAULT_INTERVAL);
+    let checkpoint = match resume {
+        true => Checkpoint::resume(path, number, part, &input, &effective, interval),
+        false => Checkpoint::create(path, number, part, &input, &effective, interval),
+    };
+    let mut checkpoint = match checkpoint {
+        Ok(checkpoint) => checkpoint,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match day
+        .solve_resumable(&input, part, params, &mut checkpoint)
+        .unwrap()
+    {
+        Ok(answer) => print_answer(day, part, &answer),
+        Err(error) => {
+            match source.path(day.number) {
+                Some(path) => eprintln!("{}", error.in_file(&path)),
+                None => eprintln!("{error}"),
+            }
+            return ExitCode::FAILURE;
+        }
+    }
+    match checkpoint.finish() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Could not save the checkpoint: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn record(day: &Day, part: Part, answer: &str, verdict: Verdict) -> ExitCode {
+    let path = Journal::path(day.number);
+    let result = Journal::read(&path).and_then(|mut journal| {
+        journal.record(part, answer, verdict);
+        journal.write(&path)
+    });
+    match result {
+        Ok(()) => {
+            println!(
+                "Day {:02} part {part}: recorded {answer} as {verdict}",
+                day.number
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn batched(
+    day: &Day,
+    dir: &Path,
+    config: &Config,
+    parts: &[Part],
+    format: Format,
+    report: Option<&Path>,
+) -> ExitCode {
+    let start = Instant::now();
+    let summaries = match batch::run(day, dir, config, parts) {
+        Ok(summaries) => summaries,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut exit_code = match batch::all_passed(&summaries) {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    };
+    match format {
+        Format::Table => batch::print_table(&summaries, start.elapsed()),
+        Format::Csv => {
+            if let Err(error) = batch::write_csv(&summaries, io::stdout().lock()) {
+                eprintln!("{error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(path) = report {
+        if let Err(error) = write_report(path, &summaries) {
+            eprintln!("{error}");
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+/// Runs the selected days and parts according to the command line arguments.
+pub fn run(days: &[Day]) -> ExitCode {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}");
+            eprintln!("Usage: {program} {USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let selected = days
+        .iter()
+        .filter(|day| options.day.is_none_or(|number| number == day.number))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        eprintln!("Day {} is not available", options.day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+    if selected.len() > 1 && matches!(options.source, Source::File(_) | Source::Stdin) {
+        eprintln!("An input file can only be given together with --day");
+        return ExitCode::from(2);
+    }
+
+    if let Some(size) = options.generate {
+        let input = selected[0].generate(options.seed.unwrap_or_default(), size);
+        return match &options.output {
+            Some(path) => match fs::write(path, input + "\n") {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("{}: {error}", path.display());
+                    ExitCode::FAILURE
+                }
+            },
+            None => {
+                println!("{input}");
+                ExitCode::SUCCESS
+            }
+        };
+    }
+
+    if let Some((answer, verdict)) = &options.record {
+        return record(selected[0], options.part.unwrap(), answer, *verdict);
+    }
+    let config = match options
+        .config
+        .as_deref()
+        .map_or_else(Config::load, Config::read)
+    {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // The parameters of the command line apply to every input of the selected day
+    let config = match options.params.is_empty() {
+        true => config,
+        false => config.with_params(options.day.unwrap(), options.params.iter().cloned()),
+    };
+
+    if options.visualize {
+        let params = params(&config, selected[0], &options.source);
+        let part = options.part.unwrap_or(Part::One);
+        return visualize(selected[0], part, &options.source, &params);
+    }
+    if let Some(path) = &options.export_frames {
+        let params = params(&config, selected[0], &options.source);
+        let part = options.part.unwrap_or(Part::One);
+        let palette = options.palette.as_deref();
+        let selection = Selection {
+            every: options.every.unwrap_or(1),
+            max_frames: options.max_frames.unwrap_or(render::DEFAULT_MAX_FRAMES),
+        };
+        return export(
+            selected[0],
+            part,
+            &options.source,
+            &params,
+            palette,
+            selection,
+            path,
+        );
+    }
+    if let Some(path) = &options.checkpoint {
+        let params = params(&config, selected[0], &options.source);
+        let part = options.part.unwrap();
+        let resume = options.resume;
+        return checkpointed(selected[0], part, &options.source, &params, path, resume);
+    }
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    if let Some(dir) = &options.batch {
+        let format = options.format.unwrap_or_default();
+        return batched(
+            selected[0],
+            dir,
+            &config,
+            &parts,
+            format,
+            options.report.as_deref(),
+        );
+    }
+    if options.all {
+        let start = Instant::now();
+        let summaries = summary::run_all(&selected, &options.source, &config, &parts);
+        summary::print_table(&summaries, start.elapsed());
+        let mut exit_code = match summary::all_passed(&summaries) {
+            true => ExitCode::SUCCESS,
+            false => ExitCode::FAILURE,
+        };
+        if let Some(path) = &options.report {
+            if let Err(error) = write_report(path, &summaries) {
+                eprintln!("{error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+        return exit_code;
+    }
+
+    if options.stats && options.day.is_some() && !selected[0].is_instrumented() {
+        eprintln!("Day {} has no searches to count", selected[0].number);
+        return ExitCode::FAILURE;
+    }
+    if options.timeout.is_some() && options.day.is_some() && !selected[0].is_cancellable() {
+        eprintln!("Day {} can not be stopped early", selected[0].number);
+        return ExitCode::FAILURE;
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut benchmarks = Vec::new();
+    // A part stopped by the timeout fails the run, even with the best answer found until then
+    let mut stopped = false;
+    if let Some(runs) = options.bench {
+        println!("{runs} runs per phase");
+        println!(
+            "Day {:<6} {:>12} {:>12} {:>12}",
+            "phase", "min", "median", "max"
+        );
+    }
+    for day in selected {
+        let input = match options.source.read(day.number) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let params = params(&config, day, &options.source);
+        if let Err(error) = day.check_params(&params) {
+            eprintln!("{error}");
+            exit_code = ExitCode::FAILURE;
+            continue;
+        }
+        // The searches are only counted with the published constants
+        if options.stats && day.is_instrumented() && !params.is_empty() {
+            eprintln!(
+                "Day {} can not count its searches with parameters",
+                day.number
+            );
+            exit_code = ExitCode::FAILURE;
+            continue;
+        }
+        // The journal only knows the answers of the puzzle input, with the published constants
+        let journal = match (&options.source, options.bench) {
+            (Source::Puzzle, None) if !params.is_empty() => {
+                eprintln!(
+                    "Day {:02} is solved with parameters, its answers are not checked against the journal",
+                    day.number
+                );
+                None
+            }
+            (Source::Puzzle, None) => match Journal::read(&Journal::path(day.number)) {
+                Ok(journal) => Some(journal),
+                Err(error) => {
+                    eprintln!("{error}");
+                    None
+                }
+            },
+            _ => None,
+        };
+        let result = match options.bench {
+            Some(runs) => day.bench(&input, &parts, runs, &params).map(|benchmark| {
+                print_benchmark(&benchmark);
+                benchmarks.push(benchmark);
+            }),
+            None => parts.iter().try_for_each(|&part| {
+                let answer = if options.stats && day.is_instrumented() {
+                    let (answer, stats) = day.solve_with_stats(&input, part).unwrap()?;
+                    print_answer(day, part, &answer);
+                    println!("  {stats}");
+                    Some(answer)
+                } else if day.is_cancellable()
+                    && (options.timeout.is_some() || io::stderr().is_terminal())
+                {
+                    let answer = solve_with_progress(day, part, &input, &params, options.timeout)?;
+                    stopped |= answer.is_none();
+                    answer
+                } else {
+                    let answer = day.solve_with_params(&input, part, &params)?;
+                    print_answer(day, part, &answer);
+                    Some(answer)
+                };
+                let warning = journal
+                    .as_ref()
+                    .zip(answer)
+                    .and_then(|(journal, answer)| journal.check(part, &answer));
+                if let Some(warning) = warning {
+                    eprintln!("  Known to be wrong: {warning}");
+                }
+                Ok(())
+            }),
+        };
+        if let Err(error) = result {
+            match options.source.path(day.number) {
+                Some(path) => eprintln!("{}", error.in_file(&path)),
+                None => eprintln!("{error}"),
+            }
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    if let Some(path) = &options.report {
+        if let Err(error) = write_report(path, &benchmarks) {
+            eprintln!("{error}");
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    if stopped {
+        exit_code = ExitCode::FAILURE;
+    }
+    exit_code
+}
@@ -0,0 +1,143 @@
+//! Parameters of the puzzles which are constants in the published ones, like the row day 15 looks at, to solve the
+//! examples of the puzzle descriptions or to try variations. They are read from `aoc.toml` in the workspace, with a
+//! table per day, and a nested table per input file for the parameters which only fit that input:
+//!
+//! ```toml
+//! [day15]
+//! row = 2000000
+//!
+//! [day15.demo_input]
+//! row = 10
+//! max_coordinate = 20
+//! ```
+//!
+//! A parameter which is not given keeps the value of the published puzzle.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::Solution;
+
+/// Parameters by name, as they are written in the configuration.
+pub type Params = toml::Table;
+
+/// The configuration of the workspace, if it has one.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("aoc.toml")
+}
+
+/// The parameters of all days.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Config {
+    days: toml::Table,
+    /// The parameters given on the command line, by day, which take precedence over the ones of the input files.
+    overrides: BTreeMap<u8, Params>,
+}
+
+impl Config {
+    /// Loads the configuration at the path.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let in_file = |error: String| format!("{}: {error}", path.display());
+        let config = fs::read_to_string(path)
+            .map_err(|error| io::Error::new(error.kind(), in_file(error.to_string())))?;
+        let config = toml::from_str(&config).map_err(|error: toml::de::Error| {
+            io::Error::new(io::ErrorKind::InvalidData, in_file(error.to_string()))
+        })?;
+        Ok(Self {
+            days: config,
+            overrides: BTreeMap::new(),
+        })
+    }
+
+    /// Loads the configuration of the workspace. Without one, every puzzle is solved as published.
+    pub fn load() -> io::Result<Self> {
+        match Self::read(&default_path()) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            config => config,
+        }
+    }
+
+    /// Adds parameters of the day which apply to all of its inputs.
+    pub fn with_params(
+        mut self,
+        day: u8,
+        params: impl IntoIterator<Item = (String, toml::Value)>,
+    ) -> Self {
+        self.overrides.entry(day).or_default().extend(params);
+        self
+    }
+
+    /// The parameters of the day, with the ones of the input file with the given name, without `.txt`, taking
+    /// precedence, and the ones added with [`Config::with_params`] over both.
+    pub fn params(&self, day: u8, input: Option<&str>) -> Params {
+        let mut params = Params::new();
+        if let Some(toml::Value::Table(table)) = self.days.get(&format!("day{day:02}")) {
+            params.extend(
+                table
+                    .iter()
+                    .filter(|(_, value)| !value.is_table())
+                    .map(|(name, value)| (name.clone(), value.clone())),
+            );
+            if let Some(toml::Value::Table(input)) = input.and_then(|input| table.get(input)) {
+                params.extend(input.clone());
+            }
+        }
+        if let Some(overrides) = self.overrides.get(&day) {
+            params.extend(overrides.clone());
+        }
+        params
+    }
+}
+
+/// Parses a parameter given as `NAME=VALUE` on the command line. The value is read like in the configuration, and as
+/// a string if it is none of the other types.
+pub fn parse_param(param: &str) -> Result<(String, toml::Value), String> {
+    let (name, value) = param
+        .split_once('=')
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or(format!("Invalid parameter '{param}', expected NAME=VALUE"))?;
+    let value = match toml::from_str::<Params>(&format!("value = {value}")) {
+        Ok(mut table) => table.remove("value").unwrap(),
+        Err(_) => toml::Value::String(value.to_owned()),
+    };
+    Ok((name.to_owned(), value))
+}
+
+/// The parameters of `S` as they are given, with the published values for the missing ones, if they are valid.
+pub(crate) fn typed<S: Configurable>(params: &Params) -> Result<S::Params, String> {
+    let invalid = |message: &str| format!("Invalid parameters of day {}: {message}", S::DAY);
+    let typed = toml::Value::Table(params.clone())
+        .try_into()
+        .map_err(|error: toml::de::Error| invalid(error.message().trim_end()))?;
+    S::check(&typed).map_err(|message| invalid(&message))?;
+    Ok(typed)
+}
+
+/// The parameters of `S` which it is solved with, like [`typed`], as they are written in the configuration.
+pub(crate) fn effective<S: Configurable>(params: &Params) -> Result<Params, String> {
+    Params::try_from(typed::<S>(params)?).map_err(|error| error.to_string())
+}
+
+/// A puzzle with constants which can be given as parameters instead.
+pub trait Configurable: Solution {
+    /// The parameters, which are the published constants by default.
+    type Params: Default + DeserializeOwned + Serialize;
+
+    /// Why the parameters can not be solved with, if they can not. By default, every value of the right type can.
+    fn check(_params: &Self::Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Solves part 1 like [`Solution::part1`] with the given parameters.
+    fn part1_with(input: &Self::Input<'_>, params: &Self::Params) -> Self::Answer1;
+
+    /// Solves part 2 like [`Solution::part2`] with the given parameters.
+    fn part2_with(input: &Self::Input<'_>, params: &Self::Params) -> Self::Answer2;
+}
@@ -0,0 +1,85 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// A malformed puzzle input, pointing to the place where parsing failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input was read from, if it was a file.
+    pub file: Option<PathBuf>,
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error in characters, starting at 1.
+    pub column: usize,
+    /// A description of what should have been there.
+    pub expected: String,
+    /// What was found instead.
+    pub found: String,
+    /// The text of the line, to point at the error.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error at `token`, which has to be a slice of `text`. Line and column are counted within `text`, so it has to be
+    /// the whole input.
+    pub fn at(text: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .expect("The token of a parse error has to be a slice of the parsed text");
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = text[offset..]
+            .find('\n')
+            .map_or(text.len(), |index| offset + index);
+        let rest = &text[offset..line_end];
+
+        let found = if !token.is_empty() {
+            format!("'{token}'")
+        } else if let Some(word) = rest.split_whitespace().next() {
+            format!("'{word}'")
+        } else if line_end < text.len() || !rest.is_empty() {
+            "end of line".to_owned()
+        } else {
+            "end of input".to_owned()
+        };
+
+        Self {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found,
+            source_line: text[line_start..line_end].to_owned(),
+        }
+    }
+
+    /// An error at the end of `text`.
+    pub fn end(text: &str, expected: impl Into<String>) -> Self {
+        Self::at(text, &text[text.len()..], expected)
+    }
+
+    pub fn in_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        writeln!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )?;
+        writeln!(f, "  | {}", self.source_line)?;
+        write!(f, "  | {:>width$}", "^", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,17 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The random number generator of the input generators. Its sequence for a seed does not depend on the platform, so a
+/// seed always stands for the same input.
+pub type Rng = ChaCha8Rng;
+
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
+
+/// Random puzzle inputs of any size, to stress-test a [`Solution`](crate::Solution) without real puzzle inputs.
+pub trait Generator {
+    /// A random input in the exact format of the puzzle. What `size` counts (lines, the side of a grid, ...) is
+    /// documented per day, larger sizes give larger inputs.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
@@ -0,0 +1,74 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum Source {
+    /// The day's `data/input.txt`.
+    #[default]
+    Puzzle,
+    /// The n-th example from the puzzle description, stored as `data/demo_input.txt` or `data/test_input.txt` (with
+    /// the number appended from the second example onwards).
+    Demo(u8),
+    File(PathBuf),
+    Stdin,
+}
+
+/// The directory holding the inputs of the given day.
+pub fn data_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{day:02}"))
+        .join("data")
+}
+
+impl Source {
+    /// The file this source reads from, if it is one.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        let data_dir = data_dir(day);
+        match self {
+            Source::Puzzle => Some(data_dir.join("input.txt")),
+            Source::Demo(number) => {
+                let suffix = match number {
+                    1 => String::new(),
+                    number => number.to_string(),
+                };
+                ["demo_input", "test_input"]
+                    .iter()
+                    .map(|name| data_dir.join(format!("{name}{suffix}.txt")))
+                    .find(|path| path.exists())
+            }
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    /// Reads the input, normalised to the format of the stored puzzle files: `\n` line endings and no trailing newline.
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        let input = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            source => {
+                let path = source.path(day).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("Day {day:02} has no such demo input"),
+                    )
+                })?;
+                fs::read_to_string(&path).map_err(|error| {
+                    io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+                })?
+            }
+        };
+        Ok(input
+            .replace("\r\n", "\n")
+            .trim_end_matches('\n')
+            .to_owned())
+    }
+}
@@ -0,0 +1,181 @@
+//! The answers submitted for the puzzle input of a day, with what the website said about them. The runner warns about
+//! a new answer which is known to be wrong already. Every day keeps its journal in `data/journal.toml`:
+//!
+//! ```toml
+//! [[part1]]
+//! answer = "1234"
+//! verdict = "too_high"
+//! ```
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{input::data_dir, Part};
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint in which direction.
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "Invalid verdict '{s}', expected correct, too-high, too-low or wrong"
+            )),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// A submitted answer.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Entry {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why a new answer can not be the right one, according to the journal.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Warning {
+    /// The same answer was submitted before and was not correct.
+    Rejected(Verdict),
+    /// Another answer was correct.
+    NotCorrect(String),
+    /// The answer is not below this one, which was too high.
+    TooHigh(String),
+    /// The answer is not above this one, which was too low.
+    TooLow(String),
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Rejected(verdict) => write!(f, "it was submitted before and was {verdict}"),
+            Warning::NotCorrect(correct) => write!(f, "{correct} was correct"),
+            Warning::TooHigh(bound) => write!(f, "{bound} was too high already"),
+            Warning::TooLow(bound) => write!(f, "{bound} was too low already"),
+        }
+    }
+}
+
+/// The submitted answers of both parts of a day, in the order they were recorded.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Journal {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Entry>,
+}
+
+impl Journal {
+    /// Where the journal of the day is kept.
+    pub fn path(day: u8) -> PathBuf {
+        data_dir(day).join("journal.toml")
+    }
+
+    /// Loads the journal at the path. If there is none, nothing was submitted yet.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let in_file = |error: String| format!("{}: {error}", path.display());
+        let journal = match fs::read_to_string(path) {
+            Ok(journal) => journal,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(io::Error::new(error.kind(), in_file(error.to_string()))),
+        };
+        toml::from_str(&journal)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, in_file(error.to_string())))
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let journal = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, journal)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+    }
+
+    /// The submitted answers of the part, oldest first.
+    pub fn entries(&self, part: Part) -> &[Entry] {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    /// Adds the answer with its verdict. An answer submitted again only gets its new verdict.
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) {
+        let entries = match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        };
+        let answer = answer.trim_end();
+        match entries.iter_mut().find(|entry| entry.answer == answer) {
+            Some(entry) => entry.verdict = verdict,
+            None => entries.push(Entry {
+                answer: answer.to_owned(),
+                verdict,
+            }),
+        }
+    }
+
+    /// Why the answer is known to be wrong, if it is. Answers are only compared with the bounds if both are integers.
+    pub fn check(&self, part: Part, answer: &str) -> Option<Warning> {
+        let answer = answer.trim_end();
+        let entries = self.entries(part);
+        if let Some(entry) = entries.iter().find(|entry| entry.answer == answer) {
+            return match entry.verdict {
+                Verdict::Correct => None,
+                verdict => Some(Warning::Rejected(verdict)),
+            };
+        }
+        if let Some(entry) = entries
+            .iter()
+            .find(|entry| entry.verdict == Verdict::Correct)
+        {
+            return Some(Warning::NotCorrect(entry.answer.clone()));
+        }
+        let value = answer.parse::<i128>().ok()?;
+        let bounds = |verdict| {
+            entries
+                .iter()
+                .filter(move |entry| entry.verdict == verdict)
+                .filter_map(|entry| Some((entry.answer.parse::<i128>().ok()?, &entry.answer)))
+        };
+        let too_high = bounds(Verdict::TooHigh).filter(|(bound, _)| value >= *bound);
+        if let Some((_, bound)) = too_high.min_by_key(|(bound, _)| *bound) {
+            return Some(Warning::TooHigh(bound.clone()));
+        }
+        let too_low = bounds(Verdict::TooLow).filter(|(bound, _)| value <= *bound);
+        too_low
+            .max_by_key(|(bound, _)| *bound)
+            .map(|(_, bound)| Warning::TooLow(bound.clone()))
+    }
+}
@@ -0,0 +1,400 @@
+use std::{fmt::Display, io, path::Path, str::FromStr};
+
+use bench::Benchmark;
+use checkpoint::{Checkpoint, Resumable};
+use config::{Configurable, Params};
+use generate::Generator;
+use progress::{Cancellable, Outcome, Progress};
+use render::{Render, Rgb, Selection};
+use stats::{Instrumented, SearchStats};
+use visualize::{Frames, Simulation};
+
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod checkpoint;
+mod cli;
+pub mod config;
+mod error;
+pub mod generate;
+pub mod input;
+pub mod journal;
+pub mod parse;
+pub mod progress;
+pub mod render;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod stats;
+pub mod summary;
+pub mod visualize;
+
+pub use cli::{run, Options};
+pub use error::ParseError;
+
+/// A single puzzle of the season, split into parsing and the two parts.
+pub trait Solution {
+    /// The day of December on which the puzzle was published.
+    const DAY: u8;
+
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+/// The answer of a puzzle which has none for some inputs that are still valid, like the length of a way which is blocked
+/// everywhere.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for MaybeAnswer<T> {
+    fn from(answer: Option<T>) -> Self {
+        Self(answer)
+    }
+}
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "no answer"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part '{s}', expected 1 or 2")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parses the input and starts the simulation solving the part.
+type Simulate = for<'a> fn(&'a str, Part) -> Result<Frames<'a>, ParseError>;
+
+/// Parses the input and writes the selected pictures of the part with the palette to the path.
+type Export = fn(&str, Part, &[Rgb], Selection, &Path) -> Result<io::Result<usize>, ParseError>;
+
+/// Parses the input and solves the part, counting the states of its searches.
+type Count = fn(&str, Part) -> Result<(String, SearchStats), ParseError>;
+
+/// Parses the input and solves the part with the parameters, saving the progress of its searches to the checkpoint.
+type Resume = fn(&str, Part, &Params, &mut Checkpoint) -> Result<String, ParseError>;
+
+/// Parses the input and solves the part with the parameters, reporting to the progress and stopping when it is
+/// cancelled.
+type Cancel = fn(&str, Part, &Params, &mut Progress) -> Result<Outcome<String>, ParseError>;
+
+/// Parses the input and solves the part with the parameters, which were checked before.
+type Configure = fn(&str, Part, &Params) -> Result<String, ParseError>;
+
+/// Times parsing and the given parts with the parameters, which were checked before.
+type BenchWith = fn(&str, &[Part], u32, &Params) -> Result<Benchmark, ParseError>;
+
+/// The check of the parameters of a day with parameters, which fills in the missing ones, and solving and timing it
+/// with them.
+#[derive(Clone, Copy)]
+struct Configurer {
+    effective: fn(&Params) -> Result<Params, String>,
+    solve: Configure,
+    bench: BenchWith,
+}
+
+/// The default palette of a day with pictures, and the export of its pictures with a palette to a path.
+#[derive(Clone, Copy)]
+struct Renderer {
+    palette: fn() -> Vec<Rgb>,
+    export: Export,
+}
+
+/// A [`Solution`] with its types erased, so that days with different inputs and answers can be run side by side.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    part1: fn(&str) -> Result<String, ParseError>,
+    part2: fn(&str) -> Result<String, ParseError>,
+    bench: fn(&str, &[Part], u32) -> Result<Benchmark, ParseError>,
+    validate: fn(&str) -> Result<(), ParseError>,
+    generate: fn(u64, usize) -> String,
+    simulate: Option<Simulate>,
+    render: Option<Renderer>,
+    count: Option<Count>,
+    resume: Option<Resume>,
+    cancel: Option<Cancel>,
+    configure: Option<Configurer>,
+}
+
+impl Day {
+    pub fn new<S: Solution + Generator>() -> Self {
+        Self {
+            number: S::DAY,
+            part1: |input| Ok(S::part1(&S::parse(input)?).to_string()),
+            part2: |input| Ok(S::part2(&S::parse(input)?).to_string()),
+            bench: Benchmark::run::<S>,
+            validate: |input| S::parse(input).map(|_| ()),
+            generate: |seed, size| S::generate(&mut generate::rng(seed), size),
+            simulate: None,
+            render: None,
+            count: None,
+            resume: None,
+            cancel: None,
+            configure: None,
+        }
+    }
+
+    /// Adds the simulation of `S`, the solution of this day, to watch it while it is solved.
+    pub fn simulated<S: Simulation>(self) -> Self {
+        Self {
+            simulate: Some(|input, part| Ok(S::frames(S::parse(input)?, part))),
+            ..self
+        }
+    }
+
+    /// Adds the pictures of `S`, the solution of this day, to export them.
+    pub fn rendered<S: Render>(self) -> Self {
+        let renderer = Renderer {
+            palette: S::palette,
+            export: |input, part, palette, selection, path| {
+                let input = S::parse(input)?;
+                let pictures = || S::pictures(&input, part);
+                Ok(render::export(pictures, palette, selection, path))
+            },
+        };
+        Self {
+            render: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Adds the counters of the searches of `S`, the solution of this day, to judge how well they are pruned.
+    pub fn instrumented<S: Instrumented>(self) -> Self {
+        Self {
+            count: Some(|input, part| {
+                let input = S::parse(input)?;
+                let mut stats = SearchStats::default();
+                let answer = match part {
+                    Part::One => S::part1_stats(&input, &mut stats).to_string(),
+                    Part::Two => S::part2_stats(&input, &mut stats).to_string(),
+                };
+                Ok((answer, stats))
+            }),
+            ..self
+        }
+    }
+
+    /// Adds the checkpoints of the searches of `S`, the solution of this day, to continue them after an interruption.
+    pub fn resumable<S: Resumable>(self) -> Self {
+        Self {
+            resume: Some(|input, part, params, checkpoint| {
+                let input = S::parse(input)?;
+                let params = &config::typed::<S>(params).unwrap_or_default();
+                Ok(match part {
+                    Part::One => S::part1_resumable(&input, params, checkpoint).to_string(),
+                    Part::Two => S::part2_resumable(&input, params, checkpoint).to_string(),
+                })
+            }),
+            ..self
+        }
+    }
+
+    /// Adds the progress of the long loops of `S`, the solution of this day, to follow them and to stop them early.
+    pub fn cancellable<S: Cancellable>(self) -> Self {
+        Self {
+            cancel: Some(|input, part, params, progress| {
+                let input = S::parse(input)?;
+                let params = &config::typed::<S>(params).unwrap_or_default();
+                Ok(match part {
+                    Part::One => S::part1_cancellable(&input, params, progress)
+                        .map(|answer| answer.to_string()),
+                    Part::Two => S::part2_cancellable(&input, params, progress)
+                        .map(|answer| answer.to_string()),
+                })
+            }),
+            ..self
+        }
+    }
+
+    /// Adds the parameters of `S`, the solution of this day, to solve it with other values than the published ones.
+    pub fn configurable<S: Configurable>(self) -> Self {
+        let configurer = Configurer {
+            effective: config::effective::<S>,
+            solve: |input, part, params| {
+                let input = S::parse(input)?;
+                let params = config::typed::<S>(params).unwrap_or_default();
+                Ok(match part {
+                    Part::One => S::part1_with(&input, &params).to_string(),
+                    Part::Two => S::part2_with(&input, &params).to_string(),
+                })
+            },
+            bench: |input, parts, runs, params| {
+                let params = config::typed::<S>(params).unwrap_or_default();
+                Benchmark::run_with::<S>(input, parts, runs, &params)
+            },
+        };
+        Self {
+            configure: Some(configurer),
+            ..self
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<String, ParseError> {
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+
+    /// Whether the day counts the states of its searches.
+    pub fn is_instrumented(&self) -> bool {
+        self.count.is_some()
+    }
+
+    /// Solves the part like [`Day::solve`] together with the stats of its searches, if the day counts them.
+    pub fn solve_with_stats(
+        &self,
+        input: &str,
+        part: Part,
+    ) -> Option<Result<(String, SearchStats), ParseError>> {
+        self.count.map(|count| count(input, part))
+    }
+
+    /// Whether the day can save the progress of its searches.
+    pub fn is_resumable(&self) -> bool {
+        self.resume.is_some()
+    }
+
+    /// Solves the part like [`Day::solve_with_params`], saving the progress to the checkpoint and continuing from the
+    /// progress saved there, if the day can.
+    pub fn solve_resumable(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Option<Result<String, ParseError>> {
+        self.resume
+            .map(|resume| resume(input, part, params, checkpoint))
+    }
+
+    /// Whether the day reports its progress and can be stopped early.
+    pub fn is_cancellable(&self) -> bool {
+        self.cancel.is_some()
+    }
+
+    /// Solves the part like [`Day::solve_with_params`], reporting to the progress and stopping with the best answer so
+    /// far when it is cancelled, if the day can.
+    pub fn solve_cancellable(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Option<Result<Outcome<String>, ParseError>> {
+        self.cancel
+            .map(|cancel| cancel(input, part, params, progress))
+    }
+
+    /// Whether the parameters fit the day. A day without parameters only takes none.
+    pub fn check_params(&self, params: &Params) -> Result<(), String> {
+        self.effective_params(params).map(|_| ())
+    }
+
+    /// The parameters the day is solved with, if they fit it: the given ones, and the published values of the others.
+    pub fn effective_params(&self, params: &Params) -> Result<Params, String> {
+        match self.configure {
+            Some(configurer) => (configurer.effective)(params),
+            None if params.is_empty() => Ok(Params::new()),
+            None => Err(format!("Day {} has no parameters", self.number)),
+        }
+    }
+
+    /// Solves the part like [`Day::solve`] with the parameters instead of the published constants. The parameters have
+    /// to pass [`Day::check_params`], otherwise the published constants are used.
+    pub fn solve_with_params(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Params,
+    ) -> Result<String, ParseError> {
+        match self.configure {
+            Some(configurer) => (configurer.solve)(input, part, params),
+            None => self.solve(input, part),
+        }
+    }
+
+    /// Times parsing and the given parts with the parameters over a number of runs. Like with
+    /// [`Day::solve_with_params`], the parameters have to pass [`Day::check_params`].
+    pub fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        runs: u32,
+        params: &Params,
+    ) -> Result<Benchmark, ParseError> {
+        match self.configure {
+            Some(configurer) => (configurer.bench)(input, parts, runs, params),
+            None => (self.bench)(input, parts, runs),
+        }
+    }
+
+    /// Only parses the input, to check whether it is well-formed.
+    pub fn validate(&self, input: &str) -> Result<(), ParseError> {
+        (self.validate)(input)
+    }
+
+    /// A random input of the given size, the same one for the same seed.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(seed, size)
+    }
+
+    /// The default colors of the pictures, if the day has any.
+    pub fn palette(&self) -> Option<Vec<Rgb>> {
+        self.render.map(|renderer| (renderer.palette)())
+    }
+
+    /// Writes the selected pictures of the part with the palette, if the day has any. See [`render::export`] for the
+    /// formats.
+    pub fn export(
+        &self,
+        input: &str,
+        part: Part,
+        palette: &[Rgb],
+        selection: Selection,
+        path: &Path,
+    ) -> Option<Result<io::Result<usize>, ParseError>> {
+        self.render
+            .map(|renderer| (renderer.export)(input, part, palette, selection, path))
+    }
+
+    /// The rendered states of the simulation solving the part, if the day is one.
+    pub fn frames<'a>(&self, input: &'a str, part: Part) -> Option<Result<Frames<'a>, ParseError>> {
+        self.simulate.map(|simulate| simulate(input, part))
+    }
+}
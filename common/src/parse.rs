@@ -0,0 +1,219 @@
+//! Parser combinators for the puzzle inputs. A parser takes the text still to parse and returns what it parsed
+//! together with the rest of the text after it, or a [`Failure`] pointing into the text. Parsers are plain functions
+//! and closures, so they are put together with `?` or with the combinators of this module. [`complete`] runs a parser
+//! on a whole input and turns a failure into a [`ParseError`] with line and column.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Where a parser failed, and what it expected there.
+#[derive(Debug)]
+pub struct Failure<'a> {
+    /// The text which was not what was expected, a slice of the parsed text. If it is empty, it only marks the place.
+    at: &'a str,
+    expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            at,
+            expected: expected.into(),
+        }
+    }
+
+    /// A failure at the start of the text, with nothing in particular to point at.
+    pub fn before(text: &'a str, expected: impl Into<String>) -> Self {
+        Self::new(&text[..0], expected)
+    }
+}
+
+/// The parsed value and the rest of the text after it.
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Parses the whole input. Only whitespace may be left over, like the line break at the end of a file.
+pub fn complete<'a, T>(
+    input: &'a str,
+    parser: impl FnOnce(&'a str) -> Parsed<'a, T>,
+) -> Result<T, ParseError> {
+    let failure = match parser(input) {
+        Ok((value, rest)) if rest.trim_end().is_empty() => return Ok(value),
+        Ok((_, rest)) => Failure::before(rest, "end of input"),
+        Err(failure) => failure,
+    };
+    Err(ParseError::at(input, failure.at, failure.expected))
+}
+
+/// The part of `before` that was parsed to get to `after`, which has to be the rest of it.
+pub fn consumed<'a>(before: &'a str, after: &str) -> &'a str {
+    &before[..before.len() - after.len()]
+}
+
+/// The given word, exactly.
+pub fn keyword<'a>(text: &'a str, word: &str) -> Parsed<'a, &'a str> {
+    match text.strip_prefix(word) {
+        Some(rest) => Ok((consumed(text, rest), rest)),
+        None => Err(Failure::before(text, format!("'{word}'"))),
+    }
+}
+
+/// The longest start of the text whose characters all match, at least one of them.
+pub fn take_while<'a>(
+    text: &'a str,
+    expected: &str,
+    predicate: impl Fn(char) -> bool,
+) -> Parsed<'a, &'a str> {
+    let end = text.find(|c| !predicate(c)).unwrap_or(text.len());
+    match end {
+        0 => Err(Failure::before(text, expected)),
+        end => Ok((&text[..end], &text[end..])),
+    }
+}
+
+/// A single character, turned into a value by `f` if it is a valid one.
+pub fn symbol<'a, T>(
+    text: &'a str,
+    expected: &str,
+    f: impl FnOnce(char) -> Option<T>,
+) -> Parsed<'a, T> {
+    let mut chars = text.chars();
+    let value = chars.next().and_then(f);
+    match value {
+        Some(value) => Ok((value, chars.as_str())),
+        None => {
+            let length = text.chars().next().map_or(0, char::len_utf8);
+            Err(Failure::new(&text[..length], expected))
+        }
+    }
+}
+
+/// A number of decimal digits, which has to fit into `T`.
+pub fn number<'a, T: FromStr>(text: &'a str, expected: &str) -> Parsed<'a, T> {
+    let (digits, rest) = take_while(text, expected, |c| c.is_ascii_digit())?;
+    match digits.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(_) => Err(Failure::new(digits, expected)),
+    }
+}
+
+/// Like [`number`], but it may be negative.
+pub fn signed<'a, T: FromStr>(text: &'a str, expected: &str) -> Parsed<'a, T> {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let (_, rest) = take_while(digits, expected, |c| c.is_ascii_digit())
+        .map_err(|_| Failure::before(text, expected))?;
+    let token = consumed(text, rest);
+    match token.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(_) => Err(Failure::new(token, expected)),
+    }
+}
+
+/// The end of a line, with or without a carriage return.
+pub fn line_break(text: &str) -> Parsed<'_, ()> {
+    match text
+        .strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+    {
+        Some(rest) => Ok(((), rest)),
+        None => Err(Failure::before(text, "end of line")),
+    }
+}
+
+/// Everything up to the end of the line, which may be nothing. The line break is not part of it.
+pub fn rest_of_line(text: &str) -> Parsed<'_, &str> {
+    let end = text.find(['\r', '\n']).unwrap_or(text.len());
+    Ok((&text[..end], &text[end..]))
+}
+
+/// The value of the parser if it succeeds, otherwise `None` without parsing anything.
+pub fn optional<'a, T>(
+    text: &'a str,
+    parser: impl FnOnce(&'a str) -> Parsed<'a, T>,
+) -> Parsed<'a, Option<T>> {
+    match parser(text) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, text)),
+    }
+}
+
+/// One or more items with the separator between them.
+pub fn separated<'a, T>(
+    text: &'a str,
+    separator: &str,
+    mut item: impl FnMut(&'a str) -> Parsed<'a, T>,
+) -> Parsed<'a, Vec<T>> {
+    let (first, mut rest) = item(text)?;
+    let mut items = vec![first];
+    while let Some(after_separator) = rest.strip_prefix(separator) {
+        let (next, after_item) = item(after_separator)?;
+        items.push(next);
+        rest = after_item;
+    }
+    Ok((items, rest))
+}
+
+/// One item per line, up to the end of the text or an empty line. Every item has to take up its whole line. The
+/// line breaks after the items are parsed, so the rest is empty or starts with the empty line.
+pub fn lines<'a, T>(
+    text: &'a str,
+    mut item: impl FnMut(&'a str) -> Parsed<'a, T>,
+) -> Parsed<'a, Vec<T>> {
+    let mut items = vec![];
+    let mut rest = text;
+    while !rest.is_empty() && line_break(rest).is_err() {
+        let (value, after_item) = item(rest)?;
+        items.push(value);
+        rest = match after_item.is_empty() {
+            true => after_item,
+            false => line_break(after_item)?.1,
+        };
+    }
+    Ok((items, rest))
+}
+
+/// One or more blocks, separated by empty lines. A block may end before or after the line break of its last line, like
+/// [`lines`] does. Empty lines at the end of the text are not parsed.
+pub fn blocks<'a, T>(
+    text: &'a str,
+    mut block: impl FnMut(&'a str) -> Parsed<'a, T>,
+) -> Parsed<'a, Vec<T>> {
+    let mut blocks = vec![];
+    let mut rest = text;
+    loop {
+        let (value, after_block) = block(rest)?;
+        blocks.push(value);
+        let mut next = after_block;
+        if !consumed(rest, after_block).ends_with('\n') {
+            match line_break(next) {
+                Ok(((), after_line)) => next = after_line,
+                Err(_) => return Ok((blocks, after_block)),
+            }
+        }
+        match line_break(next) {
+            Ok(((), after_empty_line)) if !after_empty_line.trim_end().is_empty() => {
+                rest = after_empty_line
+            }
+            _ => return Ok((blocks, after_block)),
+        }
+    }
+}
+
+/// A character map, one row per line like [`lines`]. `cell` returns `None` for characters which are not valid on the
+/// map. The rows may have different lengths.
+pub fn grid<'a, T>(
+    text: &'a str,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Parsed<'a, Vec<Vec<T>>> {
+    lines(text, |line| {
+        let (row, rest) = rest_of_line(line)?;
+        let cells = row
+            .char_indices()
+            .map(|(index, c)| {
+                cell(c).ok_or_else(|| Failure::new(&row[index..index + c.len_utf8()], expected))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((cells, rest))
+    })
+}
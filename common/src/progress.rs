@@ -0,0 +1,125 @@
+//! Reporting how far a long solver got, and stopping it early. Solvers look at the [`Progress`] in their expensive
+//! loops, and return the best answer they found so far when they are cancelled.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::config::Configurable;
+
+/// A flag to stop solvers early. All clones of a token share the same flag.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Where a solver reports which fraction of its work is done, and learns whether it should stop.
+pub struct Progress<'a> {
+    token: CancellationToken,
+    report: Box<dyn FnMut(f64) + 'a>,
+    /// The last reported fraction in thousandths, only changes of it are passed on.
+    reported: Option<u32>,
+}
+
+impl<'a> Progress<'a> {
+    /// Passes the fraction of the work done, from 0 to 1, to `report` whenever it changed by at least a thousandth.
+    pub fn new(token: CancellationToken, report: impl FnMut(f64) + 'a) -> Self {
+        Self {
+            token,
+            report: Box::new(report),
+            reported: None,
+        }
+    }
+
+    /// A progress which is never cancelled and reports nowhere, for solving without one.
+    pub fn ignored() -> Self {
+        Self::new(CancellationToken::new(), |_| {})
+    }
+
+    /// Reports the fraction of the work done, from 0 to 1.
+    pub fn report(&mut self, done: f64) {
+        let thousandths = (done.clamp(0.0, 1.0) * 1000.0) as u32;
+        if self.reported != Some(thousandths) {
+            self.reported = Some(thousandths);
+            (self.report)(done);
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// The progress of the `index`th of `count` equal sections of the work, which reports into this one.
+    pub fn section(&mut self, index: usize, count: usize) -> Progress<'_> {
+        let token = self.token.clone();
+        Progress::new(token, move |done| {
+            self.report((index as f64 + done) / count as f64)
+        })
+    }
+}
+
+/// How a solver ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    /// Stopped early, with the best answer found so far if there is one. Like the answer, it is a lower bound when the
+    /// puzzle asks for a maximum.
+    Cancelled(Option<T>),
+}
+
+impl<T> Outcome<T> {
+    /// `Cancelled` with the answer if the progress was cancelled on the way to it, otherwise `Finished`.
+    pub fn of(answer: T, progress: &Progress) -> Self {
+        match progress.is_cancelled() {
+            true => Self::Cancelled(Some(answer)),
+            false => Self::Finished(answer),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Outcome<U> {
+        match self {
+            Self::Finished(answer) => Outcome::Finished(f(answer)),
+            Self::Cancelled(answer) => Outcome::Cancelled(answer.map(f)),
+        }
+    }
+}
+
+/// For solvers which have no answer until they are done: `Finished` with the answer, or `Cancelled` without one.
+impl<T> From<Option<T>> for Outcome<T> {
+    fn from(answer: Option<T>) -> Self {
+        match answer {
+            Some(answer) => Self::Finished(answer),
+            None => Self::Cancelled(None),
+        }
+    }
+}
+
+/// A puzzle with long loops, which reports its progress and can be stopped early.
+pub trait Cancellable: Configurable {
+    /// Solves part 1 like [`Configurable::part1_with`], until it is done or the progress is cancelled.
+    fn part1_cancellable(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer1>;
+
+    /// Solves part 2 like [`Configurable::part2_with`], until it is done or the progress is cancelled.
+    fn part2_cancellable(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer2>;
+}
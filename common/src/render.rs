@@ -0,0 +1,243 @@
+//! Pictures of grid states, exported as PNG frames or as an animated GIF.
+
+use std::{
+    borrow::Cow,
+    fs::{self, File},
+    io::{self, BufWriter},
+    iter,
+    path::Path,
+};
+
+use crate::{Part, Solution};
+
+/// A color as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// The edge length of a cell in pixels.
+const SCALE: usize = 4;
+
+/// The time between the frames of an animation, in hundredths of a second.
+const DELAY: u16 = 4;
+
+/// The number of frames of an export at most, if not given explicitly. Simulations like the sand of day 14 go through
+/// tens of thousands of states, which make files of hundreds of megabytes.
+pub const DEFAULT_MAX_FRAMES: usize = 1000;
+
+/// Which of the pictures are exported as frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Selection {
+    /// Only every so many pictures become a frame, starting with the first one. The last one always does, so that the
+    /// final state is shown.
+    pub every: usize,
+    /// The number of frames at most. An export with more frames fails before writing any of them.
+    pub max_frames: usize,
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self {
+            every: 1,
+            max_frames: DEFAULT_MAX_FRAMES,
+        }
+    }
+}
+
+impl Selection {
+    fn frames<'a>(&self, pictures: Pictures<'a>) -> impl Iterator<Item = Picture> + 'a {
+        let every = self.every.max(1);
+        let mut pictures = pictures.enumerate().peekable();
+        iter::from_fn(move || loop {
+            let (index, picture) = pictures.next()?;
+            if index % every == 0 || pictures.peek().is_none() {
+                return Some(picture);
+            }
+        })
+    }
+}
+
+/// A rectangle of cells, each one holding the index of its color in the palette. The origin places the picture in
+/// the coordinates of the puzzle, so that the frames of a growing state line up.
+pub struct Picture {
+    origin: (i64, i64),
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl Picture {
+    /// Colors every cell from `min` to `max`, both inclusive, by its coordinates.
+    pub fn from_fn(
+        min: (i64, i64),
+        max: (i64, i64),
+        mut color: impl FnMut(i64, i64) -> u8,
+    ) -> Self {
+        let width = (max.0 - min.0 + 1).max(0) as usize;
+        let height = (max.1 - min.1 + 1).max(0) as usize;
+        let cells = (min.1..=max.1)
+            .flat_map(|y| (min.0..=max.0).map(move |x| (x, y)))
+            .map(|(x, y)| color(x, y))
+            .collect();
+        Self {
+            origin: min,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn max(&self) -> (i64, i64) {
+        (
+            self.origin.0 + self.width as i64 - 1,
+            self.origin.1 + self.height as i64 - 1,
+        )
+    }
+}
+
+/// Pictures of the states of a puzzle, in the order in which they occur.
+pub type Pictures<'a> = Box<dyn Iterator<Item = Picture> + 'a>;
+
+/// A puzzle with a grid worth looking at.
+pub trait Render: Solution {
+    /// The default colors of the cells. The first one is also the background of frames smaller than the largest one.
+    fn palette() -> Vec<Rgb>;
+
+    /// The states while solving the part, or a single picture if the grid does not change.
+    fn pictures<'a>(input: &'a Self::Input<'_>, part: Part) -> Pictures<'a>;
+}
+
+/// The colors evenly spread from `from` to `to`, both included.
+pub fn gradient(from: Rgb, to: Rgb, count: usize) -> Vec<Rgb> {
+    (0..count)
+        .map(|step| {
+            let fraction = step as f64 / (count.max(2) - 1) as f64;
+            [0, 1, 2].map(|channel| {
+                let (from, to) = (from[channel] as f64, to[channel] as f64);
+                (from + (to - from) * fraction).round() as u8
+            })
+        })
+        .collect()
+}
+
+/// Parses a color like `ff8800` or `#ff8800`.
+pub fn parse_color(color: &str) -> Result<Rgb, String> {
+    let hex = color.strip_prefix('#').unwrap_or(color);
+    let channel = |index: usize| {
+        hex.get(2 * index..2 * index + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+    };
+    match (hex.len(), channel(0), channel(1), channel(2)) {
+        (6, Some(red), Some(green), Some(blue)) => Ok([red, green, blue]),
+        _ => Err(format!(
+            "Invalid color '{color}', expected one like #ff8800"
+        )),
+    }
+}
+
+/// The cells of the picture within the bounds, scaled up to pixels.
+fn pixels(picture: &Picture, min: (i64, i64), max: (i64, i64)) -> Vec<u8> {
+    let width = (max.0 - min.0 + 1) as usize;
+    let height = (max.1 - min.1 + 1) as usize;
+    let mut pixels = Vec::with_capacity(width * height * SCALE * SCALE);
+    for y in 0..height {
+        let row = (0..width)
+            .flat_map(|x| {
+                let x = min.0 + x as i64 - picture.origin.0;
+                let y = min.1 + y as i64 - picture.origin.1;
+                let inside = (0..picture.width as i64).contains(&x)
+                    && (0..picture.height as i64).contains(&y);
+                let cell = match inside {
+                    true => picture.cells[y as usize * picture.width + x as usize],
+                    false => 0,
+                };
+                [cell; SCALE]
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..SCALE {
+            pixels.extend(&row);
+        }
+    }
+    pixels
+}
+
+/// Writes the selected pictures as an animated GIF if the path ends with `.gif`, otherwise as numbered PNG files into
+/// the directory at the path. All frames get the size of the union of all pictures, so the pictures are created twice:
+/// once to measure and count them and once to write them. Returns the number of frames.
+pub fn export<'a>(
+    pictures: impl Fn() -> Pictures<'a>,
+    palette: &[Rgb],
+    selection: Selection,
+    path: &Path,
+) -> io::Result<usize> {
+    let mut frames = 0;
+    let bounds = selection.frames(pictures()).fold(
+        None,
+        |bounds: Option<((i64, i64), (i64, i64))>, picture| {
+            frames += 1;
+            let (min, max) = (picture.origin, picture.max());
+            Some(match bounds {
+                Some((bounds_min, bounds_max)) => (
+                    (bounds_min.0.min(min.0), bounds_min.1.min(min.1)),
+                    (bounds_max.0.max(max.0), bounds_max.1.max(max.1)),
+                ),
+                None => (min, max),
+            })
+        },
+    );
+    let Some((min, max)) = bounds.filter(|(min, max)| min.0 <= max.0 && min.1 <= max.1) else {
+        return Ok(0);
+    };
+    if frames > selection.max_frames {
+        return Err(io::Error::other(format!(
+            "{frames} frames are more than the maximum of {}, export only every so many pictures or allow more frames",
+            selection.max_frames
+        )));
+    }
+    let width = (max.0 - min.0 + 1) as usize * SCALE;
+    let height = (max.1 - min.1 + 1) as usize * SCALE;
+    let palette = palette.concat();
+
+    let mut count = 0;
+    if path.extension().is_some_and(|extension| extension == "gif") {
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => {
+                return Err(io::Error::other(format!(
+                    "{width}x{height} pixels are too large for a GIF"
+                )))
+            }
+        };
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        for picture in selection.frames(pictures()) {
+            let frame = gif::Frame {
+                width,
+                height,
+                delay: DELAY,
+                buffer: Cow::Owned(pixels(&picture, min, max)),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+            count += 1;
+        }
+    } else {
+        fs::create_dir_all(path)?;
+        for picture in selection.frames(pictures()) {
+            let file = BufWriter::new(File::create(path.join(format!("{count:05}.png")))?);
+            let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+            encoder.set_color(png::ColorType::Indexed);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_palette(palette.as_slice());
+            let mut writer = encoder.write_header().map_err(io::Error::other)?;
+            writer
+                .write_image_data(&pixels(&picture, min, max))
+                .map_err(io::Error::other)?;
+            writer.finish().map_err(io::Error::other)?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
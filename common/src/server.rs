@@ -0,0 +1,206 @@
+//! A local HTTP service around the solvers, so that other tools can solve an input without starting a process.
+//!
+//! `POST /day/{n}` with the input as body answers with the [`DaySummary`] of the day as JSON: the answer or the error
+//! of every part, with its time. `?part=1` or `?part=2` only solves that part, any other query parameter is one of the
+//! parameters of the day, like `?row=10` for day 15, see [`crate::config`]. Parts which can be stopped early are stopped
+//! after the time limit of the server, the others are fast enough anyway. Requests which can not be served are answered
+//! with an error status and a JSON object with an `error` message.
+
+use std::{
+    io::Read,
+    net::{Ipv4Addr, SocketAddr},
+    process::ExitCode,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    config::{self, Params},
+    progress::CancellationToken,
+    summary::DaySummary,
+    Day, Part,
+};
+
+const USAGE: &str = "[--port N] [--timeout SECS]";
+
+/// The port to listen on, if not given explicitly.
+const DEFAULT_PORT: u16 = 2022;
+
+/// How long a request may take at most, if not given explicitly.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The largest input which is accepted, far above the size of any puzzle input.
+const MAX_INPUT: u64 = 16 * 1024 * 1024;
+
+/// The port and the time limit of the requests.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(u16, Duration), String> {
+    let mut port = DEFAULT_PORT;
+    let mut timeout = DEFAULT_TIMEOUT;
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for '{arg}'"));
+        match arg.as_str() {
+            "--port" | "-p" => {
+                let value = value?;
+                port = value
+                    .parse()
+                    .map_err(|_| format!("Invalid port '{value}'"))?;
+            }
+            "--timeout" => {
+                let value = value?;
+                let seconds = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+                    .ok_or(format!("Invalid timeout '{value}'"))?;
+                timeout = Duration::from_secs_f64(seconds);
+            }
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+    Ok((port, timeout))
+}
+
+#[derive(Serialize)]
+struct Error {
+    error: String,
+}
+
+/// The status and the JSON body of the response.
+type Reply = (u16, String);
+
+fn error(status: u16, message: impl Into<String>) -> Reply {
+    let error = Error {
+        error: message.into(),
+    };
+    (status, serde_json::to_string(&error).unwrap())
+}
+
+/// The parts selected by the query of the URL, both if there is none, and the parameters of the day in it.
+fn parse_query(query: Option<&str>) -> Result<(Vec<Part>, Params), String> {
+    let mut parts = Part::ALL.to_vec();
+    let mut params = Params::new();
+    for parameter in query.into_iter().flat_map(|query| query.split('&')) {
+        match parameter.split_once('=') {
+            Some(("part", part)) => parts = vec![part.parse()?],
+            _ => params.extend([config::parse_param(parameter)?]),
+        }
+    }
+    Ok((parts, params))
+}
+
+/// Solves the parts, stopping the ones which can be stopped early after the timeout.
+fn solve(day: &Day, input: &str, params: &Params, parts: &[Part], timeout: Duration) -> DaySummary {
+    let token = CancellationToken::new();
+    thread::scope(|scope| {
+        // Dropping the sender wakes the timer up when the parts are done before the timeout
+        let (done, timer) = mpsc::channel::<()>();
+        let timer_token = token.clone();
+        scope.spawn(move || {
+            if timer.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+                timer_token.cancel();
+            }
+        });
+        let summary = DaySummary::run_on(day, input, params, &token, parts);
+        drop(done);
+        summary
+    })
+}
+
+/// Solves the input with the day of the URL.
+fn reply(days: &[Day], method: &Method, url: &str, input: &str, timeout: Duration) -> Reply {
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (url, None),
+    };
+    let Some(number) = path.strip_prefix("/day/") else {
+        return error(404, format!("Unknown path '{path}', expected '/day/{{n}}'"));
+    };
+    let Some(day) = days.iter().find(|day| number.parse() == Ok(day.number)) else {
+        return error(404, format!("Day {number} is not available"));
+    };
+    if *method != Method::Post {
+        return error(405, "Only POST is supported, with the input as body");
+    }
+    let (parts, params) = match parse_query(query) {
+        Ok(query) => query,
+        Err(message) => return error(400, message),
+    };
+    if let Err(message) = day.check_params(&params) {
+        return error(400, message);
+    }
+
+    let summary = solve(day, input, &params, &parts, timeout);
+    (200, serde_json::to_string(&summary).unwrap())
+}
+
+fn handle(days: &[Day], mut request: Request, timeout: Duration) {
+    let start = Instant::now();
+    let mut input = String::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT + 1)
+        .read_to_string(&mut input);
+    let (status, body) = match read {
+        Err(_) => error(400, "The input is not valid UTF-8"),
+        Ok(_) if input.len() as u64 > MAX_INPUT => {
+            error(413, format!("The input is larger than {MAX_INPUT} bytes"))
+        }
+        Ok(_) => reply(days, request.method(), request.url(), &input, timeout),
+    };
+    println!(
+        "{} {} {status} {:.3?}",
+        request.method(),
+        request.url(),
+        start.elapsed()
+    );
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(error) = request.respond(response) {
+        eprintln!("{error}");
+    }
+}
+
+/// Serves the days on localhost until the process is stopped, with one worker per core. The port and the time limit of
+/// the requests are taken from the command line arguments.
+pub fn run(days: &[Day]) -> ExitCode {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let (port, timeout) = match parse_args(args) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}");
+            eprintln!("Usage: {program} {USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    // Only reachable from this machine, the service is not meant to be exposed.
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let server = match Server::http(address) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("{address}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Listening on http://{address}/day/{{n}}");
+
+    let workers = thread::available_parallelism().map_or(1, |workers| workers.get());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    handle(days, request, timeout);
+                }
+            });
+        }
+    });
+    ExitCode::SUCCESS
+}
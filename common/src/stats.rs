@@ -0,0 +1,45 @@
+//! Counters of the states a search goes through, to compare how well different ways of pruning it work.
+
+use std::fmt::Display;
+
+use crate::Solution;
+
+/// What a search did. Searches count only what applies to them, the other counters stay 0: a search without a set of
+/// seen states for example never finds duplicates, even if it explores the same state twice.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchStats {
+    /// The states taken from the frontier.
+    pub expanded: u64,
+    /// The states dropped by a bound or a heuristic, without exploring them.
+    pub pruned: u64,
+    /// The states dropped because they were reached before.
+    pub duplicates: u64,
+    /// The largest number of states waiting in the frontier at the same time.
+    pub peak_frontier: usize,
+}
+
+impl SearchStats {
+    /// Records the current size of the frontier.
+    pub fn frontier(&mut self, len: usize) {
+        self.peak_frontier = self.peak_frontier.max(len);
+    }
+}
+
+impl Display for SearchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} expanded, {} pruned, {} duplicates, peak frontier {}",
+            self.expanded, self.pruned, self.duplicates, self.peak_frontier
+        )
+    }
+}
+
+/// A puzzle which is solved by searching, with counters for its searches.
+pub trait Instrumented: Solution {
+    /// Solves part 1 like [`Solution::part1`], and adds up the stats of all searches on the way.
+    fn part1_stats(input: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer1;
+
+    /// Solves part 2 like [`Solution::part2`], and adds up the stats of all searches on the way.
+    fn part2_stats(input: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer2;
+}
@@ -0,0 +1,327 @@
+use std::{
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    answers::{Answers, Expected},
+    bench::nanos,
+    config::{Config, Params},
+    input::Source,
+    progress::{CancellationToken, Outcome, Progress},
+    Day, Part,
+};
+
+/// How an answer compares to the known one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// There is no known answer for this input.
+    Unknown,
+    /// The input could not be parsed or the solver panicked.
+    Error,
+    /// Not run, because the manifest lists the input but leaves out the answer of this part. Like in the answer
+    /// tests, such parts are known not to work on the input and might not even terminate.
+    Skipped,
+}
+
+impl Verdict {
+    /// The name of the verdict in reports, the same as in the JSON ones.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Unknown => "unknown",
+            Verdict::Error => "error",
+            Verdict::Skipped => "skipped",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "?"),
+            Verdict::Error => write!(f, "ERROR"),
+            Verdict::Skipped => write!(f, "skip"),
+        }
+    }
+}
+
+/// The outcome of a single part.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartSummary {
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub verdict: Verdict,
+    /// The answer, or the error message if there is none.
+    pub answer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// The time of parsing and solving.
+    #[serde(rename = "time_ns", serialize_with = "nanos")]
+    pub time: Duration,
+}
+
+fn part_number<S: serde::Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(*part as u8 + 1)
+}
+
+/// The outcome of all selected parts of a day.
+#[derive(Clone, Debug, Serialize)]
+pub struct DaySummary {
+    pub day: u8,
+    /// Why the day was not run, if its input could not be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+    pub parts: Vec<PartSummary>,
+}
+
+/// Solves the part with the parameters, turning a parse error or a panic into an error message. With a token, a day
+/// which can be stopped early is stopped when it is cancelled, which is an error as well.
+fn solve(
+    day: &Day,
+    input: &str,
+    path: Option<&Path>,
+    part: Part,
+    params: &Params,
+    token: Option<&CancellationToken>,
+) -> Result<String, String> {
+    let solve = || match token.filter(|_| day.is_cancellable()) {
+        Some(token) => {
+            let progress = &mut Progress::new(token.clone(), |_| {});
+            let outcome = day
+                .solve_cancellable(input, part, params, progress)
+                .unwrap();
+            outcome.map(|outcome| match outcome {
+                Outcome::Finished(answer) => Ok(answer),
+                Outcome::Cancelled(Some(answer)) => {
+                    Err(format!("stopped, the best answer so far is {answer}"))
+                }
+                Outcome::Cancelled(None) => Err("stopped".to_owned()),
+            })
+        }
+        None => day.solve_with_params(input, part, params).map(Ok),
+    };
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(error)) => Err(match path {
+            Some(path) => error.in_file(path).to_string(),
+            None => error.to_string(),
+        }),
+        Err(payload) => Err(payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .map_or("panicked".to_owned(), |message| {
+                format!("panicked: {message}")
+            })),
+    }
+}
+
+impl DaySummary {
+    /// Runs the day on the input of `source` and compares the answers with the ones in its manifest.
+    pub fn run(day: &Day, source: &Source, config: &Config, parts: &[Part]) -> Self {
+        // A broken manifest is reported by the answer tests, here it only means that nothing is known.
+        let answers = Answers::load(day.number).unwrap_or_default();
+        Self::run_with(day, source, &answers, config, parts)
+    }
+
+    /// Runs the day on the input of `source` with its parameters in `config` and compares the answers with the given
+    /// ones.
+    pub fn run_with(
+        day: &Day,
+        source: &Source,
+        answers: &Answers,
+        config: &Config,
+        parts: &[Part],
+    ) -> Self {
+        let input = match source.read(day.number) {
+            Ok(input) => input,
+            Err(error) => {
+                return Self {
+                    day: day.number,
+                    skipped: Some(error.to_string()),
+                    parts: Vec::new(),
+                }
+            }
+        };
+        let path = source.path(day.number);
+        // Known answers are looked up by the name of the input file, like in the manifest.
+        let name = path.as_ref().and_then(|path| {
+            path.file_stem()
+                .map(|name| name.to_string_lossy().into_owned())
+        });
+        let params = config.params(day.number, name.as_deref());
+        let known = name.and_then(|name| answers.get(&name));
+        Self::solve(day, &input, path.as_deref(), known, &params, None, parts)
+    }
+
+    /// Runs the day with the parameters on an input that does not come from a file, so no answers are known for it. The
+    /// parts which can be stopped early are stopped when the token is cancelled.
+    pub fn run_on(
+        day: &Day,
+        input: &str,
+        params: &Params,
+        token: &CancellationToken,
+        parts: &[Part],
+    ) -> Self {
+        Self::solve(day, input, None, None, params, Some(token), parts)
+    }
+
+    /// Solves the parts one after the other and compares the answers with the known ones, if any.
+    fn solve(
+        day: &Day,
+        input: &str,
+        path: Option<&Path>,
+        known: Option<&Expected>,
+        params: &Params,
+        token: Option<&CancellationToken>,
+        parts: &[Part],
+    ) -> Self {
+        // Parameters the day does not have are an error of every part, like an input it can not parse.
+        let checked = day.check_params(params);
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                if known.is_some_and(|known| known.get(part).is_none()) {
+                    return PartSummary {
+                        part,
+                        verdict: Verdict::Skipped,
+                        answer: String::new(),
+                        expected: None,
+                        time: Duration::ZERO,
+                    };
+                }
+                let start = Instant::now();
+                let result = checked
+                    .clone()
+                    .and_then(|()| solve(day, input, path, part, params, token));
+                let time = start.elapsed();
+                let (verdict, answer) = match result {
+                    Ok(answer) => {
+                        let verdict = match known.and_then(|known| known.matches(part, &answer)) {
+                            Some(true) => Verdict::Pass,
+                            Some(false) => Verdict::Fail,
+                            None => Verdict::Unknown,
+                        };
+                        (verdict, answer)
+                    }
+                    Err(error) => (Verdict::Error, error),
+                };
+                PartSummary {
+                    part,
+                    verdict,
+                    answer,
+                    expected: known.and_then(|known| known.get(part)).map(str::to_owned),
+                    time,
+                }
+            })
+            .collect();
+        Self {
+            day: day.number,
+            skipped: None,
+            parts,
+        }
+    }
+}
+
+/// Runs all given days in parallel on a thread pool. The summaries are in the order of the days.
+pub fn run_all(days: &[&Day], source: &Source, config: &Config, parts: &[Part]) -> Vec<DaySummary> {
+    days.par_iter()
+        .map(|day| DaySummary::run(day, source, config, parts))
+        .collect()
+}
+
+/// Cuts a multi-line answer or error message down to its first line, to keep the table readable.
+fn first_line(text: &str) -> String {
+    match text.split_once('\n') {
+        Some((first, _)) => format!("{first} …"),
+        None => text.to_owned(),
+    }
+}
+
+/// The columns of the part in the tables: part, result, time and the answer, with the expected one if it is wrong.
+impl Display for PartSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answer = match (&self.verdict, &self.expected) {
+            (Verdict::Fail, Some(expected)) => format!(
+                "{} (expected {})",
+                first_line(&self.answer),
+                first_line(expected)
+            ),
+            _ => first_line(&self.answer),
+        };
+        let time = match self.verdict {
+            Verdict::Skipped => String::new(),
+            _ => format!("{:.3?}", self.time),
+        };
+        write!(
+            f,
+            "{:>4} {:<6} {time:>12}  {answer}",
+            self.part.to_string(),
+            self.verdict.to_string(),
+        )
+    }
+}
+
+/// Prints one row per part and a line with the totals.
+pub fn print_table(summaries: &[DaySummary], wall_time: Duration) {
+    println!("Day Part Result         Time  Answer");
+    for summary in summaries {
+        if let Some(reason) = &summary.skipped {
+            println!("{:>3}      skipped              {}", summary.day, reason);
+        }
+        for part in &summary.parts {
+            println!("{:>3} {part}", summary.day);
+        }
+    }
+
+    print_totals(summaries, "days", wall_time);
+}
+
+/// Prints how many parts got which verdict, how many of the inputs were skipped, and the times.
+pub(crate) fn print_totals<'a>(
+    summaries: impl IntoIterator<Item = &'a DaySummary> + Clone,
+    inputs: &str,
+    wall_time: Duration,
+) {
+    let parts = || {
+        summaries
+            .clone()
+            .into_iter()
+            .flat_map(|summary| &summary.parts)
+    };
+    let count = |verdict| parts().filter(|part| part.verdict == verdict).count();
+    let skipped = summaries
+        .clone()
+        .into_iter()
+        .filter(|summary| summary.skipped.is_some())
+        .count();
+    let total = parts().map(|part| part.time).sum::<Duration>();
+    println!(
+        "{} passed, {} failed, {} unknown, {} errors, {} parts and {skipped} {inputs} skipped in {wall_time:.3?} ({total:.3?} of solving)",
+        count(Verdict::Pass),
+        count(Verdict::Fail),
+        count(Verdict::Unknown),
+        count(Verdict::Error),
+        count(Verdict::Skipped),
+    );
+}
+
+/// Whether no answer was wrong and no day failed to parse.
+pub fn all_passed<'a>(summaries: impl IntoIterator<Item = &'a DaySummary>) -> bool {
+    summaries
+        .into_iter()
+        .flat_map(|summary| &summary.parts)
+        .all(|part| !matches!(part.verdict, Verdict::Fail | Verdict::Error))
+}
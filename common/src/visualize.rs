@@ -0,0 +1,275 @@
+//! Watching simulations in the terminal, step by step or played at an adjustable speed, forwards and backwards.
+
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+
+use crate::{Part, Solution};
+
+/// Rendered states of a simulation, in the order in which they occur.
+pub type Frames<'a> = Box<dyn Iterator<Item = String> + 'a>;
+
+/// A puzzle which is solved by a simulation that is worth watching.
+pub trait Simulation: Solution {
+    /// The states of the simulation while solving the part, rendered as text: the initial state first, then the one
+    /// after every step. The frames end with the state the answer is taken from.
+    fn frames(input: Self::Input<'_>, part: Part) -> Frames<'_>;
+}
+
+/// How many bytes of frames are kept to rewind to. Older frames are dropped, so that long simulations with large
+/// states do not eat up the memory.
+const HISTORY_BYTES: usize = 512 * 1024 * 1024;
+
+const HELP: &str =
+    "space play/pause  ←/→ step  r rewind  home start  +/- speed  wasd scroll  q quit";
+
+/// The frames seen so far, as far as they are kept, and the ones still to come.
+struct History<'a> {
+    upcoming: Frames<'a>,
+    frames: VecDeque<String>,
+    bytes: usize,
+    /// The step of the first kept frame.
+    first: usize,
+    /// The step of the shown frame.
+    current: usize,
+    finished: bool,
+}
+
+impl<'a> History<'a> {
+    fn new(mut upcoming: Frames<'a>) -> Self {
+        let frames = VecDeque::from_iter(upcoming.next());
+        Self {
+            bytes: frames.iter().map(String::len).sum(),
+            upcoming,
+            frames,
+            first: 0,
+            current: 0,
+            finished: false,
+        }
+    }
+
+    fn frame(&self) -> &str {
+        self.frames
+            .get(self.current - self.first)
+            .map_or("", String::as_str)
+    }
+
+    /// Moves to the next step, returns false at the end of the simulation.
+    fn forward(&mut self) -> bool {
+        if self.current + 1 < self.first + self.frames.len() {
+            self.current += 1;
+            return true;
+        }
+        if self.finished {
+            return false;
+        }
+        match self.upcoming.next() {
+            Some(frame) => {
+                self.bytes += frame.len();
+                self.frames.push_back(frame);
+                self.current += 1;
+                while self.bytes > HISTORY_BYTES && self.first < self.current {
+                    self.bytes -= self.frames.pop_front().unwrap().len();
+                    self.first += 1;
+                }
+                true
+            }
+            None => {
+                self.finished = true;
+                false
+            }
+        }
+    }
+
+    /// Moves to the previous step, returns false at the oldest kept frame.
+    fn back(&mut self) -> bool {
+        if self.current > self.first {
+            self.current -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Paused,
+    Playing,
+    Rewinding,
+}
+
+/// Restores the terminal when the player is left, even by an error.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+struct Player<'a> {
+    title: String,
+    history: History<'a>,
+    mode: Mode,
+    steps_per_second: u32,
+    /// The top left corner of the shown part of the frame.
+    scroll: (usize, usize),
+}
+
+impl Player<'_> {
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+
+        let mode = match self.mode {
+            Mode::Paused => "paused",
+            Mode::Playing => "playing",
+            Mode::Rewinding => "rewinding",
+        };
+        let steps = match self.history.finished {
+            true => format!("{}", self.history.first + self.history.frames.len() - 1),
+            false => "?".to_owned(),
+        };
+        let status = format!(
+            "{}  step {}/{steps}  {mode} at {} steps/s",
+            self.title, self.history.current, self.steps_per_second
+        );
+
+        queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+        queue!(out, Print(clip(&status, 0, width)))?;
+        let lines = self.history.frame().lines().skip(self.scroll.1);
+        for (row, line) in lines.take(height.saturating_sub(2)).enumerate() {
+            queue!(
+                out,
+                cursor::MoveTo(0, row as u16 + 1),
+                Print(clip(line, self.scroll.0, width))
+            )?;
+        }
+        queue!(
+            out,
+            cursor::MoveTo(0, height.saturating_sub(1) as u16),
+            Print(clip(HELP, 0, width))
+        )?;
+        out.flush()
+    }
+
+    /// Handles a key press, returns false if the player should be left.
+    fn press(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            // The terminal does not turn it into a signal while the player runs
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(' ') => {
+                self.mode = match self.mode {
+                    Mode::Paused => Mode::Playing,
+                    _ => Mode::Paused,
+                }
+            }
+            KeyCode::Char('r') => self.mode = Mode::Rewinding,
+            KeyCode::Right => {
+                self.mode = Mode::Paused;
+                self.history.forward();
+            }
+            KeyCode::Left => {
+                self.mode = Mode::Paused;
+                self.history.back();
+            }
+            KeyCode::Home => {
+                self.mode = Mode::Paused;
+                while self.history.back() {}
+            }
+            KeyCode::Char('+') => self.steps_per_second = (self.steps_per_second * 2).min(4096),
+            KeyCode::Char('-') => self.steps_per_second = (self.steps_per_second / 2).max(1),
+            KeyCode::Char('w') => self.scroll.1 = self.scroll.1.saturating_sub(5),
+            KeyCode::Char('s') => self.scroll.1 += 5,
+            KeyCode::Char('a') => self.scroll.0 = self.scroll.0.saturating_sub(10),
+            KeyCode::Char('d') => self.scroll.0 += 10,
+            _ => {}
+        }
+        true
+    }
+
+    /// Plays or rewinds the given number of steps, and pauses at either end.
+    fn advance(&mut self, steps: u32) {
+        for _ in 0..steps {
+            let moved = match self.mode {
+                Mode::Paused => return,
+                Mode::Playing => self.history.forward(),
+                Mode::Rewinding => self.history.back(),
+            };
+            if !moved {
+                self.mode = Mode::Paused;
+            }
+        }
+    }
+}
+
+/// The part of the line starting at the given column which fits into the width.
+fn clip(line: &str, start: usize, width: usize) -> String {
+    line.chars().skip(start).take(width).collect()
+}
+
+/// Shows the frames in the terminal until the user quits. It starts paused at the first frame.
+pub fn play(frames: Frames, title: String) -> io::Result<()> {
+    let mut player = Player {
+        title,
+        history: History::new(frames),
+        mode: Mode::Paused,
+        steps_per_second: 8,
+        scroll: (0, 0),
+    };
+
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+    let mut last_step = Instant::now();
+    loop {
+        player.draw(&mut out)?;
+
+        // Wait for the next step, but redraw at least a few times per second in case the terminal was resized
+        let step = Duration::from_secs(1) / player.steps_per_second;
+        let timeout = match player.mode {
+            Mode::Paused => Duration::from_millis(250),
+            _ => step.saturating_sub(last_step.elapsed()),
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !player.press(key) {
+                    return Ok(());
+                }
+            }
+        }
+
+        if player.mode == Mode::Paused {
+            last_step = Instant::now();
+        } else if last_step.elapsed() >= step {
+            // Fast speeds take several steps per drawn frame
+            let steps = (last_step.elapsed().as_nanos() / step.as_nanos()) as u32;
+            player.advance(steps);
+            last_step += step * steps;
+            // Drop the steps that could not be taken in time, instead of catching up with them forever
+            if last_step.elapsed() >= step {
+                last_step = Instant::now();
+            }
+        }
+    }
+}
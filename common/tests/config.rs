@@ -0,0 +1,45 @@
+//! How the parameters of the configuration, of the input files and of the command line take precedence over each other.
+
+use common::config::{parse_param, Config};
+
+#[test]
+fn parameters_of_inputs_and_command_line_take_precedence() {
+    let path = std::env::temp_dir().join(format!("aoc-{}.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        "[day15]\nrow = 2000000\nmax_coordinate = 4000000\n\n[day15.demo_input]\nrow = 10\n",
+    )
+    .unwrap();
+    let config = Config::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let param = |params: &common::config::Params, name: &str| params[name].as_integer();
+    let published = config.params(15, Some("input"));
+    assert_eq!(param(&published, "row"), Some(2000000));
+    assert!(!published.contains_key("demo_input"));
+    let demo = config.params(15, Some("demo_input"));
+    assert_eq!(param(&demo, "row"), Some(10));
+    assert_eq!(param(&demo, "max_coordinate"), Some(4000000));
+    assert!(config.params(7, None).is_empty());
+
+    let config = config.with_params(15, [parse_param("row=7").unwrap()]);
+    assert_eq!(
+        param(&config.params(15, Some("demo_input")), "row"),
+        Some(7)
+    );
+    assert!(config.params(7, None).is_empty());
+}
+
+#[test]
+fn parses_parameters_of_the_command_line() {
+    assert_eq!(
+        parse_param("knots=2"),
+        Ok(("knots".to_owned(), toml::Value::Integer(2)))
+    );
+    assert_eq!(
+        parse_param("name=demo"),
+        Ok(("name".to_owned(), toml::Value::String("demo".to_owned())))
+    );
+    assert!(parse_param("=2").is_err());
+    assert!(parse_param("knots").is_err());
+}
@@ -0,0 +1,54 @@
+//! Which new answers the journal knows to be wrong, and that it survives being written and read again.
+
+use common::{
+    journal::{Journal, Verdict, Warning},
+    Part,
+};
+
+#[test]
+fn warns_about_answers_known_to_be_wrong() {
+    let mut journal = Journal::default();
+    journal.record(Part::One, "5000", Verdict::TooHigh);
+    journal.record(Part::One, "2000", Verdict::TooHigh);
+    journal.record(Part::One, "1000", Verdict::TooLow);
+    journal.record(Part::One, "1500", Verdict::Wrong);
+
+    let check = |answer| journal.check(Part::One, answer);
+    assert_eq!(check("1500"), Some(Warning::Rejected(Verdict::Wrong)));
+    assert_eq!(check("3000"), Some(Warning::TooHigh("2000".to_owned())));
+    assert_eq!(check("900"), Some(Warning::TooLow("1000".to_owned())));
+    assert_eq!(check("1234"), None);
+    assert_eq!(check("ABC"), None);
+    assert_eq!(journal.check(Part::Two, "5000"), None);
+
+    journal.record(Part::One, "1234", Verdict::Correct);
+    assert_eq!(journal.check(Part::One, "1234\n"), None);
+    assert_eq!(
+        journal.check(Part::One, "1235"),
+        Some(Warning::NotCorrect("1234".to_owned()))
+    );
+}
+
+#[test]
+fn keeps_the_order_of_the_answers() {
+    let mut journal = Journal::default();
+    journal.record(Part::Two, "12", Verdict::TooLow);
+    journal.record(Part::Two, "ABC\nDEF", Verdict::Wrong);
+    journal.record(Part::Two, "12", Verdict::Wrong);
+
+    let path = std::env::temp_dir().join(format!("journal-{}.toml", std::process::id()));
+    journal.write(&path).unwrap();
+    let read = Journal::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read, journal);
+    let answers = read
+        .entries(Part::Two)
+        .iter()
+        .map(|entry| (entry.answer.as_str(), entry.verdict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        answers,
+        [("12", Verdict::Wrong), ("ABC\nDEF", Verdict::Wrong)]
+    );
+    assert!(read.entries(Part::One).is_empty());
+}
@@ -0,0 +1,45 @@
+//! The parser combinators on small inputs, and where their errors point to.
+
+use common::parse::{self, blocks, keyword, lines, number, separated, signed, Parsed};
+
+fn point(text: &str) -> Parsed<'_, (i32, i32)> {
+    let (x, text) = signed(text, "a number")?;
+    let (_, text) = keyword(text, ",")?;
+    let (y, rest) = signed(text, "a number")?;
+    Ok(((x, y), rest))
+}
+
+#[test]
+fn parses_lines_and_blocks() {
+    let input = "1,2 -> -3,4\n5,6\n\n7,8\r\n";
+    let walls = parse::complete(input, |text| {
+        blocks(text, |block| {
+            lines(block, |line| separated(line, " -> ", point))
+        })
+    });
+    assert_eq!(
+        walls,
+        Ok(vec![
+            vec![vec![(1, 2), (-3, 4)], vec![(5, 6)]],
+            vec![vec![(7, 8)]]
+        ])
+    );
+    assert_eq!(parse::complete("", |text| lines(text, point)), Ok(vec![]));
+}
+
+#[test]
+fn points_to_the_error() {
+    let error = parse::complete("1,2\n3;4\n", |text| lines(text, point)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.expected, "','");
+
+    let error = parse::complete("12\n999\n", |text| {
+        lines(text, |line| number::<u8>(line, "a byte"))
+    })
+    .unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.found, "'999'");
+
+    let error = parse::complete("1,2 3,4", point).unwrap_err();
+    assert_eq!(error.expected, "end of input");
+}
@@ -0,0 +1,40 @@
+//! Which pictures become frames of an export, and that too many of them are not written at all.
+
+use common::render::{self, Picture, Pictures, Selection};
+
+/// A growing line of cells, one picture per length.
+fn pictures(count: i64) -> impl Fn() -> Pictures<'static> {
+    move || Box::new((0..count).map(|length| Picture::from_fn((0, 0), (length, 0), |_, _| 1)))
+}
+
+#[test]
+fn exports_every_nth_picture_and_the_last_one() {
+    let path = std::env::temp_dir().join(format!("frames-{}", std::process::id()));
+    let palette = [[0, 0, 0], [255, 255, 255]];
+    let selection = Selection {
+        every: 3,
+        max_frames: 10,
+    };
+    // The pictures 0, 3 and 6, and the last one
+    assert_eq!(
+        render::export(pictures(8), &palette, selection, &path).unwrap(),
+        4
+    );
+    assert_eq!(std::fs::read_dir(&path).unwrap().count(), 4);
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn refuses_to_export_more_frames_than_allowed() {
+    let path = std::env::temp_dir().join(format!("frames-{}.gif", std::process::id()));
+    let palette = [[0, 0, 0], [255, 255, 255]];
+    let selection = Selection {
+        every: 1,
+        max_frames: 5,
+    };
+    let error = render::export(pictures(6), &palette, selection, &path).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("6 frames are more than the maximum of 5"));
+    assert!(!path.exists());
+}
@@ -0,0 +1,19 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day01;
+
+/// `size` is the number of elves, each carrying one to ten snacks.
+impl Generator for Day01 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..rng.random_range(1..=10))
+                    .map(|_| rng.random_range(1000..=60000).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
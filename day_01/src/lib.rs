@@ -0,0 +1,38 @@
+use common::{
+    parse::{self, blocks, lines, number},
+    ParseError, Solution,
+};
+use itertools::Itertools;
+
+mod generator;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let calories = parse::complete(input, |text| {
+            blocks(text, |block| {
+                let (calories, rest) = lines(block, |line| number::<u32>(line, "a number"))?;
+                Ok((calories.into_iter().sum::<u32>(), rest))
+            })
+        })?;
+        Ok(calories
+            .into_iter()
+            .sorted_by_key(|&v| std::cmp::Reverse(v))
+            .collect())
+    }
+
+    fn part1(calories: &Self::Input<'_>) -> Self::Answer1 {
+        calories.iter().copied().max().unwrap_or_default()
+    }
+
+    fn part2(calories: &Self::Input<'_>) -> Self::Answer2 {
+        calories.iter().take(3).sum()
+    }
+}
@@ -1,21 +1,7 @@
-use itertools::Itertools;
+use std::process::ExitCode;
 
-fn main() {
-    let input = include_str!("../data/input.txt");
+use common::Day;
 
-    let (solution1, solution2) = input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .split('\n')
-                .map(|line| line.parse::<u32>().unwrap())
-                .sum::<u32>()
-        })
-        .sorted_by_key(|&v| std::cmp::Reverse(v))
-        .take(3)
-        .fold((u32::MIN, u32::default()), |(max, sum), value| {
-            (max.max(value), sum + value)
-        });
-
-    println!("Solution 1: {solution1}, solution 2: {solution2}");
+fn main() -> ExitCode {
+    common::run(&[Day::new::<day_01::Day01>()])
 }
@@ -0,0 +1,18 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day02;
+
+/// `size` is the number of rounds.
+impl Generator for Day02 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let theirs = (b'A' + rng.random_range(0..3)) as char;
+                let ours = (b'X' + rng.random_range(0..3)) as char;
+                format!("{theirs} {ours}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
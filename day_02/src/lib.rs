@@ -0,0 +1,157 @@
+use std::str::FromStr;
+
+use common::{
+    parse::{self, keyword, lines, symbol, Parsed},
+    ParseError, Solution,
+};
+
+mod generator;
+
+#[derive(Clone, Copy)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl TryFrom<char> for Move {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' | 'X' => Ok(Move::Rock),
+            'B' | 'Y' => Ok(Move::Paper),
+            'C' | 'Z' => Ok(Move::Scissors),
+            _ => Err("Invalid character to parse"),
+        }
+    }
+}
+
+impl Move {
+    pub fn inherent_points(&self) -> u32 {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3,
+        }
+    }
+
+    pub fn outcome(&self, theirs: &Move) -> Outcome {
+        if self.beats(theirs) {
+            Outcome::Win
+        } else if theirs.beats(self) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn beats(&self, theirs: &Move) -> bool {
+        matches!(
+            (self, theirs),
+            (Move::Rock, Move::Scissors)
+                | (Move::Paper, Move::Rock)
+                | (Move::Scissors, Move::Paper)
+        )
+    }
+}
+
+pub struct Round {
+    pub theirs: Move,
+    pub ours: Move,
+}
+
+/// Parses a line of the strategy guide: their move, and our move or the outcome, which share the second column.
+fn round(text: &str) -> Parsed<'_, (Round, Outcome)> {
+    let (theirs, text) = symbol(text, "A, B or C", |c| c.try_into().ok())?;
+    let (_, text) = keyword(text, " ")?;
+    let ((ours, outcome), rest) = symbol(text, "X, Y or Z", |c| {
+        Some((c.try_into().ok()?, c.try_into().ok()?))
+    })?;
+    Ok(((Round { theirs, ours }, outcome), rest))
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::complete(line, round).map(|(round, _)| round)
+    }
+}
+
+impl Round {
+    pub fn outcome(&self) -> Outcome {
+        self.ours.outcome(&self.theirs)
+    }
+    pub fn our_score(&self) -> u32 {
+        self.ours.inherent_points() + self.outcome().inherent_points()
+    }
+
+    pub fn with_outcome(theirs: Move, outcome: &Outcome) -> Round {
+        let ours = match (theirs, outcome) {
+            (_, Outcome::Draw) => theirs,
+            (Move::Rock, Outcome::Loss) => Move::Scissors,
+            (Move::Rock, Outcome::Win) => Move::Paper,
+            (Move::Paper, Outcome::Loss) => Move::Rock,
+            (Move::Paper, Outcome::Win) => Move::Scissors,
+            (Move::Scissors, Outcome::Loss) => Move::Paper,
+            (Move::Scissors, Outcome::Win) => Move::Rock,
+        };
+        Round { theirs, ours }
+    }
+}
+
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn inherent_points(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Self::Loss),
+            'Y' => Ok(Self::Draw),
+            'Z' => Ok(Self::Win),
+            _ => Err("Invalid character for parsing to an Outcome."),
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<(Round, Outcome)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| lines(text, round))
+    }
+
+    fn part1(guide: &Self::Input<'_>) -> Self::Answer1 {
+        guide.iter().map(|(round, _)| round.our_score()).sum()
+    }
+
+    fn part2(guide: &Self::Input<'_>) -> Self::Answer2 {
+        guide
+            .iter()
+            .map(|(round, outcome)| Round::with_outcome(round.theirs, outcome))
+            .map(|round| round.our_score())
+            .sum()
+    }
+}
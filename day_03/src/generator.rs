@@ -0,0 +1,51 @@
+use common::generate::{Generator, Rng};
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng as _,
+};
+
+use crate::Day03;
+
+/// A rucksack, whose compartments only share `shared` and which contains `badge`. All other items are taken from
+/// `pool`.
+fn rucksack(rng: &mut Rng, pool: &[char], badge: char, length: usize) -> String {
+    let shared = *pool.choose(rng).unwrap();
+    let others = pool
+        .iter()
+        .copied()
+        .filter(|item| *item != shared)
+        .collect::<Vec<_>>();
+    // Items of the first half come from the first half of the other items, so that only `shared` is in both.
+    let (first_items, second_items) = others.split_at(others.len() / 2);
+    let mut first = vec![shared, badge];
+    first.extend((2..length).map(|_| *first_items.choose(rng).unwrap()));
+    let mut second = vec![shared];
+    second.extend((1..length).map(|_| *second_items.choose(rng).unwrap()));
+    first.shuffle(rng);
+    second.shuffle(rng);
+    first.into_iter().chain(second).collect()
+}
+
+/// `size` is the number of groups of three elves.
+impl Generator for Day03 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+        (0..size.max(1))
+            .flat_map(|_| {
+                // Every elf of the group takes its items from its own pool, so that the badge is the only item all of
+                // them carry.
+                let mut items = items.clone();
+                items.shuffle(rng);
+                let (badge, items) = items.split_first().unwrap();
+                items
+                    .chunks(items.len() / 3)
+                    .map(|pool| {
+                        let length = rng.random_range(2..=16);
+                        rucksack(rng, pool, *badge, length)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,103 @@
+use common::{
+    parse::{self, lines, take_while, Failure},
+    ParseError, Solution,
+};
+pub use item::Item;
+use itertools::Itertools;
+
+mod generator;
+
+mod item {
+    pub struct Item(pub char);
+
+    impl Item {
+        pub fn priority(self) -> u32 {
+            match self.0 {
+                'a'..='z' => self.0.to_digit(36).unwrap() - 10 + 1,
+                'A'..='Z' => self.0.to_digit(36).unwrap() - 10 + 1 + 26,
+                _ => unreachable!("Should never happen"),
+            }
+        }
+    }
+
+    impl From<char> for Item {
+        fn from(c: char) -> Self {
+            Self(c)
+        }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| {
+            let (rucksacks, rest) = lines(text, |line| {
+                let (items, rest) = take_while(line, "an item from a to z or A to Z", |c| {
+                    c.is_ascii_alphabetic()
+                })?;
+                if items.len() % 2 == 1 {
+                    return Err(Failure::before(rest, "an even number of items"));
+                }
+                let (first, second) = items.split_at(items.len() / 2);
+                if !first.contains(|c| second.contains(c)) {
+                    return Err(Failure::new(
+                        items,
+                        "a rucksack with an item in both compartments",
+                    ));
+                }
+                Ok((items, rest))
+            })?;
+            // A group which does not fit is pointed at with its first rucksack
+            for group in rucksacks.chunks(3) {
+                match group {
+                    [first, second, third] => {
+                        if !first.contains(|c| second.contains(c) && third.contains(c)) {
+                            let expected = "a group of three rucksacks with a common item";
+                            return Err(Failure::new(first, expected));
+                        }
+                    }
+                    _ => return Err(Failure::new(group[0], "a group of three rucksacks")),
+                }
+            }
+            Ok((rucksacks, rest))
+        })
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> Self::Answer1 {
+        rucksacks
+            .iter()
+            .map(|line| {
+                let s1 = &line[..line.len() / 2];
+                let s2 = &line[line.len() / 2..];
+
+                let item = Item::from(s1.chars().find(|c| s2.contains(*c)).unwrap());
+                item.priority()
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> Self::Answer2 {
+        rucksacks
+            .iter()
+            .chunks(3)
+            .into_iter()
+            .map(|mut group| {
+                let l1 = group.next().unwrap();
+                let l2 = group.next().unwrap();
+                let l3 = group.next().unwrap();
+                let c = l1
+                    .chars()
+                    .find(|c| l2.contains(*c) && l3.contains(*c))
+                    .unwrap();
+                Item::from(c).priority()
+            })
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,19 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day04;
+
+/// `size` is the number of pairs of elves.
+impl Generator for Day04 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.random_range(1..=99);
+            let end = rng.random_range(start..=99);
+            format!("{start}-{end}")
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}", range(), range()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
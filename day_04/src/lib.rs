@@ -0,0 +1,53 @@
+use std::ops::RangeInclusive;
+
+use common::{
+    parse::{self, keyword, lines, number, Parsed},
+    ParseError, Solution,
+};
+
+mod generator;
+
+fn range(text: &str) -> Parsed<'_, RangeInclusive<u32>> {
+    let (start, text) = number(text, "a section")?;
+    let (_, text) = keyword(text, "-")?;
+    let (end, rest) = number(text, "a section")?;
+    Ok((start..=end, rest))
+}
+
+fn pair(text: &str) -> Parsed<'_, (RangeInclusive<u32>, RangeInclusive<u32>)> {
+    let (first, text) = range(text)?;
+    let (_, text) = keyword(text, ",")?;
+    let (second, rest) = range(text)?;
+    Ok(((first, second), rest))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| lines(text, pair))
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Self::Answer1 {
+        pairs
+            .iter()
+            .filter(|(r1, r2)| {
+                (r1.start() >= r2.start() && r1.end() <= r2.end())
+                    || (r2.start() >= r1.start() && r2.end() <= r1.end())
+            })
+            .count()
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Self::Answer2 {
+        pairs
+            .iter()
+            .filter(|(r1, r2)| r1.contains(r2.start()) || r2.contains(r1.start()))
+            .count()
+    }
+}
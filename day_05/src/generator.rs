@@ -0,0 +1,51 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::IndexedRandom, Rng as _};
+
+use crate::Day05;
+
+const STACK_COUNT: usize = 9;
+
+/// `size` is the number of moves. No move empties a stack, so that every stack has a crate on top in the end. As
+/// there are more crates than stacks, some stack always has crates to spare.
+impl Generator for Day05 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut heights = (0..STACK_COUNT)
+            .map(|_| rng.random_range(2..=8))
+            .collect::<Vec<usize>>();
+        let max_height = *heights.iter().max().unwrap();
+        let mut lines = (0..max_height)
+            .rev()
+            .map(|level| {
+                let line = heights
+                    .iter()
+                    .map(|height| match level < *height {
+                        true => format!("[{}]", rng.random_range('A'..='Z')),
+                        false => "   ".to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                line.trim_end().to_owned()
+            })
+            .collect::<Vec<_>>();
+        lines.push(
+            (1..=STACK_COUNT)
+                .map(|stack| format!(" {stack} "))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        lines.push(String::new());
+
+        for _ in 0..size.max(1) {
+            let sources = (0..STACK_COUNT)
+                .filter(|stack| heights[*stack] > 1)
+                .collect::<Vec<_>>();
+            let from = *sources.choose(rng).unwrap();
+            let to = (from + rng.random_range(1..STACK_COUNT)) % STACK_COUNT;
+            let count = rng.random_range(1..heights[from]);
+            heights[from] -= count;
+            heights[to] += count;
+            lines.push(format!("move {count} from {} to {}", from + 1, to + 1));
+        }
+        lines.join("\n")
+    }
+}
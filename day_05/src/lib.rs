@@ -0,0 +1,127 @@
+use common::{
+    parse::{self, consumed, keyword, line_break, lines, number, rest_of_line, Failure, Parsed},
+    ParseError, Solution,
+};
+
+mod generator;
+
+#[derive(Debug)]
+pub struct Command {
+    pub count: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub type Commands = Vec<Command>;
+pub type Stack = Vec<char>;
+pub type Stacks = Vec<Stack>;
+
+fn stacks(text: &str) -> Parsed<'_, Stacks> {
+    let (lines, rest) = lines(text, rest_of_line)?;
+    let Some((labels, crates)) = lines.split_last() else {
+        return Err(Failure::before(text, "a drawing of the stacks"));
+    };
+    let mut stacks = vec![Stack::new(); labels.split_whitespace().count()];
+
+    for line in crates.iter().rev() {
+        for (stack_index, stack) in stacks.iter_mut().enumerate() {
+            let x_pos = stack_index * 4;
+            if line.get(x_pos..x_pos + 1) != Some("[") {
+                continue;
+            }
+            let label = line
+                .get(x_pos + 1..x_pos + 2)
+                .unwrap_or(&line[line.len()..]);
+            match label.chars().next() {
+                Some(c) if c.is_ascii_uppercase() => stack.push(c),
+                _ => return Err(Failure::new(label, "a crate from A to Z")),
+            }
+        }
+    }
+    Ok((stacks, rest))
+}
+
+fn stack(text: &str, stack_count: usize) -> Parsed<'_, usize> {
+    let expected = format!("a stack from 1 to {stack_count}");
+    let (stack, rest) = number(text, &expected)?;
+    match (1..=stack_count).contains(&stack) {
+        true => Ok((stack, rest)),
+        false => Err(Failure::new(consumed(text, rest), expected)),
+    }
+}
+
+/// A move, which can not take more crates than the stack it is from holds, with `heights` the crates of every stack.
+fn command<'a>(text: &'a str, heights: &[usize]) -> Parsed<'a, Command> {
+    let (_, text) = keyword(text, "move ")?;
+    let (count, after_count) = number(text, "a number of crates")?;
+    let (_, rest) = keyword(after_count, " from ")?;
+    let (from, rest) = stack(rest, heights.len())?;
+    let (_, rest) = keyword(rest, " to ")?;
+    let (to, rest) = stack(rest, heights.len())?;
+    let height = heights[from - 1];
+    if count as usize > height {
+        let expected = format!("a number of crates from 0 to {height}");
+        return Err(Failure::new(consumed(text, after_count), expected));
+    }
+    Ok((Command { count, from, to }, rest))
+}
+
+fn parse(input: &str) -> Result<(Stacks, Commands), ParseError> {
+    parse::complete(input, |text| {
+        let (stacks, text) = stacks(text)?;
+        let (_, text) = line_break(text)
+            .map_err(|_| Failure::before(text, "an empty line between the stacks and the moves"))?;
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        let (commands, rest) = lines(text, |line| {
+            let (command, rest) = command(line, &heights)?;
+            heights[command.from - 1] -= command.count as usize;
+            heights[command.to - 1] += command.count as usize;
+            Ok((command, rest))
+        })?;
+        Ok(((stacks, commands), rest))
+    })
+}
+
+/// The crates on top of the stacks, from left to right, leaving out the empty stacks.
+pub fn top_crates(stacks: &Stacks) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Stacks, Commands);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((stacks, commands): &Self::Input<'_>) -> Self::Answer1 {
+        let mut stacks = stacks.clone();
+        for command in commands {
+            for _ in 0..command.count {
+                let c = stacks[command.from - 1].pop().unwrap();
+                stacks[command.to - 1].push(c);
+            }
+        }
+        top_crates(&stacks)
+    }
+
+    fn part2((stacks, commands): &Self::Input<'_>) -> Self::Answer2 {
+        let mut stacks = stacks.clone();
+        for command in commands {
+            let old_stack_size = stacks[command.from - 1].len();
+            let (x, y) =
+                stacks[command.from - 1].split_at_mut(old_stack_size - command.count as usize);
+            let remainder = x.to_vec();
+            let to_move = y.to_vec();
+            stacks[command.from - 1] = remainder;
+            stacks[command.to - 1].append(&mut to_move.to_vec());
+        }
+        top_crates(&stacks)
+    }
+}
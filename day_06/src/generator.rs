@@ -0,0 +1,21 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Day06;
+
+/// `size` is the length of the datastream. The markers only show up after about half of it: before, there are just
+/// three different characters.
+impl Generator for Day06 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let noise = ['a', 'b', 'c'];
+        let prefix = size.saturating_sub(14) / 2;
+        let mut marker = ('a'..='z').collect::<Vec<_>>();
+        marker.shuffle(rng);
+        let mut stream = (0..prefix)
+            .map(|_| noise[rng.random_range(0..noise.len())])
+            .collect::<String>();
+        stream.extend(marker.into_iter().take(14));
+        stream.extend((prefix + 14..size).map(|_| rng.random_range('a'..='z')));
+        stream
+    }
+}
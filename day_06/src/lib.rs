@@ -0,0 +1,49 @@
+use common::{
+    parse::{self, take_while, Failure},
+    ParseError, Solution,
+};
+use itertools::Itertools;
+
+mod generator;
+
+/// The number of characters read until the last `length` of them are all different, if they ever are.
+pub fn solution(length: usize, input: &str) -> Option<usize> {
+    let position = input
+        .as_bytes()
+        .windows(length)
+        .position(|x| x.iter().unique().count() == x.len())?;
+    Some(position + length)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        const EXPECTED: &str = "a character from a to z";
+        parse::complete(input, |text| {
+            let (signal, rest) = take_while(text, EXPECTED, |c| c.is_ascii_lowercase())?;
+            match rest.trim_end().chars().next() {
+                Some(c) => Err(Failure::new(&rest[..c.len_utf8()], EXPECTED)),
+                None if solution(14, signal).is_none() => Err(Failure::before(
+                    rest,
+                    "a start-of-message marker of 14 different characters",
+                )),
+                None => Ok((signal, rest)),
+            }
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        solution(4, input).unwrap()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        solution(14, input).unwrap()
+    }
+}
@@ -0,0 +1,78 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day07;
+
+fn name(rng: &mut Rng) -> String {
+    (0..rng.random_range(1..=8))
+        .map(|_| rng.random_range('a'..='z'))
+        .collect()
+}
+
+/// Lists the folder and then changes into each of its subfolders.
+fn browse(
+    rng: &mut Rng,
+    folder: usize,
+    children: &[Vec<usize>],
+    files: &[Vec<usize>],
+    lines: &mut Vec<String>,
+) {
+    let names = children[folder]
+        .iter()
+        .map(|_| name(rng))
+        .collect::<Vec<_>>();
+    lines.push("$ ls".to_owned());
+    for name in &names {
+        lines.push(format!("dir {name}"));
+    }
+    for size in &files[folder] {
+        lines.push(format!("{size} {}.{}", name(rng), name(rng)));
+    }
+    for (child, name) in children[folder].iter().zip(names) {
+        lines.push(format!("$ cd {name}"));
+        browse(rng, *child, children, files, lines);
+        lines.push("$ cd ..".to_owned());
+    }
+}
+
+/// `size` is the number of folders, at least two as only subfolders can be deleted. The files take up between 42000000
+/// and 68000000 of the disk of 70000000, so that some folder has to be deleted. To always have one that is large
+/// enough, the first subfolder holds a file of 30000000.
+impl Generator for Day07 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let folders = size.max(2);
+        let mut children = vec![Vec::new(); folders];
+        for folder in 1..folders {
+            children[rng.random_range(0..folder)].push(folder);
+        }
+        let mut weights = (0..folders)
+            .map(|_| {
+                (0..rng.random_range(0..=5))
+                    .map(|_| rng.random_range(1..=100))
+                    .collect::<Vec<usize>>()
+            })
+            .collect::<Vec<_>>();
+        weights[0].push(rng.random_range(1..=100));
+
+        let total_weight = weights.iter().flatten().sum::<usize>();
+        let total_size = rng.random_range(12000000..=38000000);
+        let mut files = weights
+            .iter()
+            .map(|weights| {
+                weights
+                    .iter()
+                    .map(|weight| (weight * total_size / total_weight).max(1))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        files[1].push(30000000);
+
+        let mut lines = vec!["$ cd /".to_owned()];
+        browse(rng, 0, &children, &files, &mut lines);
+        // Like in the puzzle input, the session ends in the last folder, without going back up.
+        while lines.last().is_some_and(|line| line == "$ cd ..") {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+}
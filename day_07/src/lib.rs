@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use common::{
+    config::Configurable,
+    parse::{self, keyword, line_break, lines, number, optional, rest_of_line, Parsed},
+    ParseError, Solution,
+};
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+#[derive(Clone)]
+pub struct File(pub usize);
+
+#[derive(Default, Clone)]
+pub struct Folder {
+    pub files: Vec<File>,
+    pub dirs: Vec<Folder>,
+}
+
+pub trait Size {
+    fn size(&self) -> usize;
+}
+
+impl Size for File {
+    fn size(&self) -> usize {
+        self.0
+    }
+}
+
+impl Size for Folder {
+    fn size(&self) -> usize {
+        self.files.iter().map(|file| file.0).sum::<usize>()
+            + self.dirs.iter().fold(0, |sum, dir| sum + dir.size())
+    }
+}
+
+/// A line of the terminal output. Only the sizes of the files and the changes of the directory matter.
+enum Output {
+    Into,
+    Up,
+    List,
+    File(usize),
+}
+
+fn output(text: &str) -> Parsed<'_, Output> {
+    if let Ok((_, rest)) = keyword(text, "$ cd ..") {
+        return Ok((Output::Up, rest));
+    }
+    if let Ok((_, rest)) = keyword(text, "$ cd ") {
+        return Ok((Output::Into, rest_of_line(rest)?.1));
+    }
+    if let Ok((_, rest)) = keyword(text, "$ ls") {
+        return Ok((Output::List, rest));
+    }
+    if let Ok((_, rest)) = keyword(text, "dir ") {
+        return Ok((Output::List, rest_of_line(rest)?.1));
+    }
+    let (size, rest) = number(text, "a command, a directory or a file size")?;
+    Ok((Output::File(size), rest_of_line(rest)?.1))
+}
+
+fn build_folder(outputs: &mut impl Iterator<Item = Output>) -> Folder {
+    let mut folder = Folder::default();
+    while let Some(output) = outputs.next() {
+        match output {
+            Output::Into => folder.dirs.push(build_folder(outputs)),
+            Output::Up => return folder,
+            Output::List => {}
+            Output::File(size) => folder.files.push(File(size)),
+        }
+    }
+    folder
+}
+
+impl FromStr for Folder {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse::complete(input, |text| {
+            let (_, text) = keyword(text, "$ cd /")?;
+            let (_, text) = optional(text, line_break)?;
+            let (outputs, rest) = lines(text, output)?;
+            Ok((build_folder(&mut outputs.into_iter()), rest))
+        })
+    }
+}
+
+impl Folder {
+    fn get_folders(&self) -> Vec<Folder> {
+        if self.dirs.is_empty() {
+            return vec![];
+        }
+        let mut dirs = self.dirs.clone();
+        for dir in &self.dirs {
+            dirs.append(&mut dir.get_folders());
+        }
+        dirs
+    }
+}
+
+/// The sizes of the disk, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub disk_size: usize,
+    /// The free space the update needs.
+    pub needed_space: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            disk_size: 70000000,
+            needed_space: 30000000,
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Folder;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse::<Folder>()
+    }
+
+    fn part1(root: &Self::Input<'_>) -> Self::Answer1 {
+        root.get_folders()
+            .iter()
+            .map(|dir| dir.size())
+            .filter(|size| size <= &100000)
+            .sum::<usize>()
+    }
+
+    fn part2(root: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(root, &Params::default())
+    }
+}
+
+impl Configurable for Day07 {
+    type Params = Params;
+
+    fn check(params: &Params) -> Result<(), String> {
+        match params.disk_size >= params.needed_space {
+            true => Ok(()),
+            false => Err("the disk is smaller than the needed_space".to_owned()),
+        }
+    }
+
+    fn part1_with(root: &Self::Input<'_>, _: &Params) -> Self::Answer1 {
+        Self::part1(root)
+    }
+
+    fn part2_with(root: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        let used_disk_space = root.size();
+        let free_disk_space = params.disk_size.saturating_sub(used_disk_space);
+        let required_disk_space = params.needed_space.saturating_sub(free_disk_space);
+
+        // Deleting everything frees enough space on a disk which is at least as large as the needed space
+        root.get_folders()
+            .iter()
+            .map(|dir| dir.size())
+            .chain([used_disk_space])
+            .filter(|size| size >= &required_disk_space)
+            .min()
+            .unwrap()
+    }
+}
@@ -0,0 +1,19 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day08;
+
+/// `size` is the side of the square forest.
+impl Generator for Day08 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| char::from_digit(rng.random_range(0..10), 10).unwrap())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
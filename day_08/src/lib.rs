@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use common::{
+    parse::{self, Failure},
+    ParseError, Solution,
+};
+use geometry::Direction;
+use grid::{Grid, Point};
+
+mod generator;
+mod render;
+
+#[derive(Default, PartialEq, PartialOrd)]
+pub struct Tree(pub u8);
+
+pub struct Trees(Grid<Tree>);
+
+impl Tree {
+    pub fn is_higher_than_all<'a>(&self, mut others: impl Iterator<Item = &'a Tree>) -> bool {
+        others.all(|other| self > other)
+    }
+
+    pub fn count_visible_trees<'a>(&self, others: impl Iterator<Item = &'a Tree>) -> u64 {
+        others
+            .scan(false, |found, tree| {
+                if *found {
+                    None
+                } else {
+                    *found = tree >= self;
+                    Some(tree)
+                }
+            })
+            .count() as u64
+    }
+}
+
+impl FromStr for Trees {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        const EXPECTED: &str = "a tree height from 0 to 9";
+        parse::complete(input, |text| {
+            let (grid, rest) = Grid::parse(text, EXPECTED, |c| {
+                c.to_digit(10).map(|digit| Tree(digit as u8))
+            })?;
+            match grid.width() {
+                0 => Err(Failure::before(text, EXPECTED)),
+                _ => Ok((grid, rest)),
+            }
+        })
+        .map(Trees)
+    }
+}
+
+impl Trees {
+    /// The lines of sight from a tree to the four edges of the forest.
+    fn lines_of_sight(&self, position: Point) -> [impl Iterator<Item = &Tree>; 4] {
+        Direction::ALL.map(|direction| self.0.ray(position, direction).map(|(_, tree)| tree))
+    }
+
+    fn outside_visible_trees(&self) -> u64 {
+        self.0
+            .iter()
+            .filter(|(position, tree)| {
+                self.lines_of_sight(*position)
+                    .into_iter()
+                    .any(|others| tree.is_higher_than_all(others))
+            })
+            .count() as u64
+    }
+
+    fn max_scenic_score(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|(position, tree)| {
+                self.lines_of_sight(position)
+                    .into_iter()
+                    .map(|others| tree.count_visible_trees(others))
+                    .product::<u64>()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Trees;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse::<Trees>()
+    }
+
+    fn part1(trees: &Self::Input<'_>) -> Self::Answer1 {
+        trees.outside_visible_trees()
+    }
+
+    fn part2(trees: &Self::Input<'_>) -> Self::Answer2 {
+        trees.max_scenic_score()
+    }
+}
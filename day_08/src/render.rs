@@ -0,0 +1,23 @@
+use std::iter;
+
+use common::{
+    render::{gradient, Picture, Pictures, Render, Rgb},
+    Part,
+};
+use grid::Point;
+
+use crate::{Day08, Trees};
+
+/// A single picture of the heights of the trees, the same for both parts.
+impl Render for Day08 {
+    fn palette() -> Vec<Rgb> {
+        gradient([0x10, 0x30, 0x10], [0xb0, 0xf0, 0x80], 10)
+    }
+
+    fn pictures<'a>(trees: &'a Self::Input<'_>, _part: Part) -> Pictures<'a> {
+        let Trees(grid) = trees;
+        let max = (grid.width() as i64 - 1, grid.height() as i64 - 1);
+        let picture = Picture::from_fn((0, 0), max, |x, y| grid[Point::new(x, y)].0);
+        Box::new(iter::once(picture))
+    }
+}
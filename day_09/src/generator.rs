@@ -0,0 +1,17 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::IndexedRandom, Rng as _};
+
+use crate::Day09;
+
+/// `size` is the number of motions of the head.
+impl Generator for Day09 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let direction = ["U", "D", "L", "R"].choose(rng).unwrap();
+                format!("{direction} {}", rng.random_range(1..=20))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,139 @@
+use std::{collections::HashSet, iter, str::FromStr};
+
+use common::{
+    config::Configurable,
+    parse::{self, keyword, lines, number, symbol, Parsed},
+    ParseError, Solution,
+};
+use geometry::{Direction, Point};
+use serde::{Deserialize, Serialize};
+
+mod generator;
+mod simulation;
+
+pub struct Command {
+    pub direction: Direction,
+    pub count: u8,
+}
+
+pub type Position = Point<i32>;
+
+pub type Rope = Vec<Position>;
+
+fn command(text: &str) -> Parsed<'_, Command> {
+    let (direction, text) = symbol(text, "U, D, L or R", |c| match c {
+        'U' => Some(Direction::Up),
+        'D' => Some(Direction::Down),
+        'L' => Some(Direction::Left),
+        'R' => Some(Direction::Right),
+        _ => None,
+    })?;
+    let (_, text) = keyword(text, " ")?;
+    let (count, rest) = number(text, "a number of steps")?;
+    Ok((Command { direction, count }, rest))
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::complete(line, command)
+    }
+}
+
+fn get_new_knot(head: &Position, tail: &Position) -> Position {
+    let horizontal_diff = head.x - tail.x;
+    let vertical_diff = head.y - tail.y;
+    assert!(horizontal_diff <= 2);
+    assert!(vertical_diff <= 2);
+
+    if horizontal_diff.abs() == 2 || vertical_diff.abs() == 2 {
+        *tail + Position::new(horizontal_diff.signum(), vertical_diff.signum())
+    } else {
+        *tail
+    }
+}
+
+fn update_rope(rope: &mut Rope) {
+    let head = rope[0];
+    rope.iter_mut()
+        .skip(1)
+        .scan(head, |prev, knot| {
+            *knot = get_new_knot(prev, knot);
+            *prev = *knot;
+            Some(*knot)
+        })
+        .for_each(|_| {});
+}
+
+/// The number of positions visited by the tail of a rope with `length` knots.
+pub fn tail_positions(commands: &[Command], length: usize) -> usize {
+    let mut rope = Rope::new();
+    rope.resize(length, Position::default());
+
+    let tails = commands
+        .iter()
+        .flat_map(|command| iter::repeat_n(command.direction, command.count as usize))
+        .scan(rope, |rope, direction| {
+            rope[0] += direction;
+            update_rope(rope);
+            Some(*(rope.last()).unwrap())
+        })
+        .collect::<HashSet<_>>();
+    tails.len()
+}
+
+/// The rope of part 2, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The number of knots of the long rope, with head and tail.
+    pub knots: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { knots: 10 }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| lines(text, command))
+    }
+
+    fn part1(commands: &Self::Input<'_>) -> Self::Answer1 {
+        tail_positions(commands, 2)
+    }
+
+    fn part2(commands: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(commands, &Params::default())
+    }
+}
+
+impl Configurable for Day09 {
+    type Params = Params;
+
+    fn check(params: &Params) -> Result<(), String> {
+        match params.knots >= 1 {
+            true => Ok(()),
+            false => Err("a rope needs at least 1 knot".to_owned()),
+        }
+    }
+
+    fn part1_with(commands: &Self::Input<'_>, _: &Params) -> Self::Answer1 {
+        Self::part1(commands)
+    }
+
+    fn part2_with(commands: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        tail_positions(commands, params.knots)
+    }
+}
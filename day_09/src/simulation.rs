@@ -0,0 +1,63 @@
+use std::{collections::HashSet, iter};
+
+use common::{
+    visualize::{Frames, Simulation},
+    Part,
+};
+
+use crate::{update_rope, Day09, Position, Rope};
+
+/// Renders the rope like in the puzzle: `H` is the head, the other knots are numbered, or `T` for the tail of a rope
+/// with two knots. `#` marks the positions visited by the tail and `s` the start.
+fn render(rope: &Rope, visited: &HashSet<Position>) -> String {
+    let points = || rope.iter().chain(visited);
+    let (min_x, max_x) = (
+        points().map(|p| p.x).min().unwrap(),
+        points().map(|p| p.x).max().unwrap(),
+    );
+    let (min_y, max_y) = (
+        points().map(|p| p.y).min().unwrap(),
+        points().map(|p| p.y).max().unwrap(),
+    );
+
+    (min_y..=max_y)
+        .flat_map(|y| {
+            (min_x..=max_x)
+                .map(move |x| {
+                    let position = Position::new(x, y);
+                    match rope.iter().position(|knot| *knot == position) {
+                        Some(0) => 'H',
+                        Some(_) if rope.len() == 2 => 'T',
+                        Some(knot) => char::from_digit(knot as u32, 36).unwrap(),
+                        None if position == Position::default() => 's',
+                        None if visited.contains(&position) => '#',
+                        None => '.',
+                    }
+                })
+                .chain(iter::once('\n'))
+        })
+        .collect()
+}
+
+/// One frame per step of the head, with the rope of two knots in part 1 and of ten knots in part 2.
+impl Simulation for Day09 {
+    fn frames(commands: Self::Input<'_>, part: Part) -> Frames<'_> {
+        let length = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        let mut rope = vec![Position::default(); length];
+        let mut visited = HashSet::from([Position::default()]);
+        let first = render(&rope, &visited);
+        let steps = commands
+            .into_iter()
+            .flat_map(|command| iter::repeat_n(command.direction, command.count as usize))
+            .map(move |direction| {
+                rope[0] += direction;
+                update_rope(&mut rope);
+                visited.insert(*rope.last().unwrap());
+                render(&rope, &visited)
+            });
+        Box::new(iter::once(first).chain(steps))
+    }
+}
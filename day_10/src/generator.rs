@@ -0,0 +1,25 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day10;
+
+/// `size` is the number of cycles, at least the 240 of the screen. The register stays around the width of the screen.
+impl Generator for Day10 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = Vec::new();
+        let mut register = 1;
+        let mut cycles = 0;
+        while cycles < size.max(240) {
+            if rng.random_bool(0.3) {
+                lines.push("noop".to_owned());
+                cycles += 1;
+            } else {
+                let value = rng.random_range(-5..=45) - register;
+                register += value;
+                lines.push(format!("addx {value}"));
+                cycles += 2;
+            }
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use common::{
+    parse::{self, keyword, lines, signed, Failure},
+    ParseError, Solution,
+};
+
+mod generator;
+
+pub enum Command {
+    Addx(i16),
+    Noop,
+}
+
+/// The value of the register during the given cycle.
+pub fn value_at_cycle(values: &[(usize, i16)], cycle: usize) -> i16 {
+    let index = values
+        .iter()
+        .take_while(|(cyc, _)| cyc < &cycle)
+        .last()
+        .map_or(1, |(_, value)| *value);
+    index
+}
+
+/// The cycles at which the register changes, with its new value.
+pub fn intermediate_values(commands: &[Command]) -> Vec<(usize, i16)> {
+    let mut cycle_counter = 0;
+    let mut reg_value = 1;
+
+    commands
+        .iter()
+        .map(|command| {
+            match command {
+                Command::Addx(delta) => {
+                    reg_value += *delta;
+                    cycle_counter += 2
+                }
+                Command::Noop => cycle_counter += 1,
+            };
+            (cycle_counter, reg_value)
+        })
+        .collect::<Vec<_>>()
+}
+
+pub struct Screen(Vec<bool>);
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, line) in self.0.chunks(40).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for pixel in line {
+                write!(f, "{}", if *pixel { '█' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Command>;
+    type Answer1 = i16;
+    type Answer2 = Screen;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| {
+            lines(text, |line| {
+                if let Ok((_, rest)) = keyword(line, "noop") {
+                    Ok((Command::Noop, rest))
+                } else if let Ok((_, count)) = keyword(line, "addx ") {
+                    let (count, rest) = signed(count, "a number")?;
+                    Ok((Command::Addx(count), rest))
+                } else {
+                    Err(Failure::before(line, "'noop' or 'addx'"))
+                }
+            })
+        })
+    }
+
+    fn part1(commands: &Self::Input<'_>) -> Self::Answer1 {
+        let intermediate_values = intermediate_values(commands);
+        let cycles = [20, 60, 100, 140, 180, 220];
+        let signal_strengths = cycles.iter().map(|current_cycle| {
+            let value = value_at_cycle(&intermediate_values, *current_cycle);
+            value * (*current_cycle as i16)
+        });
+        signal_strengths.sum::<i16>()
+    }
+
+    fn part2(commands: &Self::Input<'_>) -> Self::Answer2 {
+        let intermediate_values = intermediate_values(commands);
+        let pixels = (1..241)
+            .map(|cycle| {
+                let value = value_at_cycle(&intermediate_values, cycle);
+                let pixel = ((cycle - 1) as i16) % 40;
+                (pixel - value).abs() <= 1
+            })
+            .collect::<Vec<_>>();
+        Screen(pixels)
+    }
+}
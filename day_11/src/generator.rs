@@ -0,0 +1,122 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Day11;
+
+const MONKEY_COUNT: usize = 8;
+
+/// The tests of the monkeys. As their product is used to keep the worry levels small, it has to stay below 2^32 for
+/// squaring the worry levels not to overflow.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> Option<u64> {
+        match self {
+            Operation::Add(value) => old.checked_add(value),
+            Operation::Multiply(value) => old.checked_mul(value),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    targets: [usize; 2],
+}
+
+fn monkeys(rng: &mut Rng, item_count: usize) -> Vec<Monkey> {
+    let mut tests = PRIMES;
+    tests.shuffle(rng);
+    let mut monkeys = tests
+        .iter()
+        .take(MONKEY_COUNT)
+        .enumerate()
+        .map(|(id, test)| {
+            let operation = match rng.random_range(0..10) {
+                0 => Operation::Square,
+                1..=4 => Operation::Multiply(rng.random_range(2..=19)),
+                _ => Operation::Add(rng.random_range(1..=8)),
+            };
+            let mut targets = [0, 0];
+            while targets[0] == targets[1] || targets.contains(&id) {
+                targets = [0, 0].map(|_| rng.random_range(0..MONKEY_COUNT));
+            }
+            Monkey {
+                items: vec![rng.random_range(50..100)],
+                operation,
+                test: *test,
+                targets,
+            }
+        })
+        .collect::<Vec<_>>();
+    for _ in MONKEY_COUNT..item_count {
+        let monkey = rng.random_range(0..MONKEY_COUNT);
+        monkeys[monkey].items.push(rng.random_range(50..100));
+    }
+    monkeys
+}
+
+/// Whether the 20 rounds of part 1 work without overflow. There, worry levels are only divided by three, which does
+/// not stop them from growing when an item keeps getting squared.
+fn fits(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    for _ in 0..20 {
+        for (id, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[id]) {
+                let Some(item) = monkey.operation.apply(item) else {
+                    return false;
+                };
+                let item = item / 3;
+                let target = monkey.targets[usize::from(item % monkey.test != 0)];
+                items[target].push(item);
+            }
+        }
+    }
+    true
+}
+
+/// `size` is the number of items, spread over eight monkeys that have at least one item each.
+impl Generator for Day11 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let monkeys = loop {
+            let monkeys = monkeys(rng, size);
+            if fits(&monkeys) {
+                break monkeys;
+            }
+        };
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(id, monkey)| {
+                let items = monkey
+                    .items
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let operation = match monkey.operation {
+                    Operation::Add(value) => format!("old + {value}"),
+                    Operation::Multiply(value) => format!("old * {value}"),
+                    Operation::Square => "old * old".to_owned(),
+                };
+                format!(
+                    "Monkey {id}:\n  Starting items: {items}\n  Operation: new = {operation}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    monkey.test, monkey.targets[0], monkey.targets[1]
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
@@ -0,0 +1,169 @@
+use common::{
+    config::Configurable,
+    parse::{self, blocks, keyword, line_break, number, separated, symbol, Parsed},
+    ParseError, Solution,
+};
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+pub struct Monkey {
+    items: Vec<u64>,
+    inspect: Box<dyn Fn(u64) -> u64>,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+fn operation(text: &str) -> Parsed<'_, Box<dyn Fn(u64) -> u64>> {
+    let (_, text) = keyword(text, "old ")?;
+    let (multiply, text) = symbol(text, "'*' or '+'", |c| match c {
+        '*' => Some(true),
+        '+' => Some(false),
+        _ => None,
+    })?;
+    let (_, text) = keyword(text, " ")?;
+    let (operand, rest) = match keyword(text, "old") {
+        Ok((_, rest)) => (None, rest),
+        Err(_) => {
+            number::<u64>(text, "a number or 'old'").map(|(value, rest)| (Some(value), rest))?
+        }
+    };
+    let inspect: Box<dyn Fn(u64) -> u64> = match (multiply, operand) {
+        (true, None) => Box::new(|old| old * old),
+        (false, None) => Box::new(|old| old + old),
+        (true, Some(value)) => Box::new(move |old| old * value),
+        (false, Some(value)) => Box::new(move |old| old + value),
+    };
+    Ok((inspect, rest))
+}
+
+/// The next line of a monkey's description, which has to start with `prefix` after the indentation.
+fn field<'a, T>(
+    text: &'a str,
+    prefix: &str,
+    value: impl FnOnce(&'a str) -> Parsed<'a, T>,
+) -> Parsed<'a, T> {
+    let (_, text) = line_break(text)?;
+    let (_, text) = keyword(text.trim_start_matches(' '), prefix)?;
+    value(text)
+}
+
+fn monkey(text: &str) -> Parsed<'_, Monkey> {
+    let (_, text) = keyword(text, "Monkey ")?;
+    let (_, text) = number::<usize>(text, "a monkey number")?;
+    let (_, text) = keyword(text, ":")?;
+    let (items, text) = field(text, "Starting items: ", |text| {
+        separated(text, ", ", |item| number(item, "a worry level"))
+    })?;
+    let (inspect, text) = field(text, "Operation: new = ", operation)?;
+    let (test, text) = field(text, "Test: divisible by ", |text| number(text, "a number"))?;
+    let monkey_number = |text| number(text, "a monkey number");
+    let (if_true, text) = field(text, "If true: throw to monkey ", monkey_number)?;
+    let (if_false, rest) = field(text, "If false: throw to monkey ", monkey_number)?;
+    let monkey = Monkey {
+        items,
+        inspect,
+        test,
+        if_true,
+        if_false,
+    };
+    Ok((monkey, rest))
+}
+
+/// The product of the two highest numbers of inspected items after the given rounds.
+pub fn monkey_business(monkeys: &[Monkey], round_count: usize, relief: bool) -> usize {
+    let mut items = monkeys
+        .iter()
+        .enumerate()
+        .flat_map(|(id, monkey)| {
+            monkey
+                .items
+                .iter()
+                .map(|item| (id, *item))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut item_count = vec![0; monkeys.len()];
+
+    let lcm = monkeys.iter().map(|monkey| monkey.test).product::<u64>();
+
+    for _x in 0..round_count {
+        for (monkey_id, monkey) in monkeys.iter().enumerate() {
+            let new_items = items
+                .iter()
+                .map(|(id, item)| {
+                    if id != &monkey_id {
+                        return (*id, *item);
+                    }
+                    item_count[monkey_id] += 1;
+                    let new_item = if relief {
+                        (monkey.inspect)(*item) / 3
+                    } else {
+                        ((monkey.inspect)(*item)) % lcm
+                    };
+                    if new_item % monkey.test == 0 {
+                        (monkey.if_true, new_item)
+                    } else {
+                        (monkey.if_false, new_item)
+                    }
+                })
+                .collect::<Vec<_>>();
+            items = new_items;
+        }
+    }
+
+    item_count.sort();
+    item_count.reverse();
+
+    item_count[0] * item_count[1]
+}
+
+/// The length of part 2, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The rounds of part 2, without relief.
+    pub rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { rounds: 10000 }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| blocks(text, monkey))
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Self::Answer1 {
+        monkey_business(monkeys, 20, true)
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(monkeys, &Params::default())
+    }
+}
+
+impl Configurable for Day11 {
+    type Params = Params;
+
+    fn part1_with(monkeys: &Self::Input<'_>, _: &Params) -> Self::Answer1 {
+        Self::part1(monkeys)
+    }
+
+    fn part2_with(monkeys: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        monkey_business(monkeys, params.rounds, false)
+    }
+}
@@ -0,0 +1,30 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day12;
+
+/// `size` is the side of the square heightmap, at least 26 to fit all heights. The terrain rises from west to east
+/// with some noise. One row is an even ramp from the start to the end, so that there is always a path.
+impl Generator for Day12 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(26);
+        let path_row = rng.random_range(0..size);
+        let ramp = |x: usize| (x * 25 / (size - 1)) as i64;
+        (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| match (y == path_row, x) {
+                        (true, 0) => 'S',
+                        (true, x) if x == size - 1 => 'E',
+                        (true, x) => (b'a' + ramp(x) as u8) as char,
+                        (false, x) => {
+                            let height = (ramp(x) + rng.random_range(-3..=3)).clamp(0, 25);
+                            (b'a' + height as u8) as char
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use common::{parse, stats::Instrumented, MaybeAnswer, ParseError, Solution};
+use grid::{Grid, Point};
+use search::SearchStats;
+
+mod generator;
+mod render;
+
+pub struct Map(Grid<u8>);
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let map = parse::complete(input, |text| {
+            Grid::parse(text, "a height from a to z, S or E", |c| {
+                (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c as u8)
+            })
+        })
+        .map(Map)?;
+        match (map.start(), map.end()) {
+            (None, _) => Err(ParseError::end(input, "the start S")),
+            (_, None) => Err(ParseError::end(input, "the end E")),
+            _ => Ok(map),
+        }
+    }
+}
+
+impl Map {
+    pub fn start(&self) -> Option<Point> {
+        self.0.position(|c| *c == b'S')
+    }
+
+    pub fn end(&self) -> Option<Point> {
+        self.0.position(|c| *c == b'E')
+    }
+
+    pub fn find_lowest_fields(&self) -> Vec<Point> {
+        self.0
+            .iter()
+            .filter(|(_, field)| **field == b'a' || **field == b'S')
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>()
+    }
+
+    pub fn height(&self, field: Point) -> u8 {
+        match self.0[field] {
+            b'S' => b'a',
+            b'E' => b'z',
+            height => height,
+        }
+    }
+}
+
+/// The number of steps from the nearest of the start fields to the end, if it can be reached at all.
+pub fn shortest_path(map: &Map, start: Vec<Point>) -> Option<usize> {
+    shortest_path_with_stats(map, start, &mut SearchStats::default())
+}
+
+/// Like [`shortest_path`], counting the fields of the search into `stats`.
+pub fn shortest_path_with_stats(
+    map: &Map,
+    start: Vec<Point>,
+    stats: &mut SearchStats,
+) -> Option<usize> {
+    let end = map.end().unwrap();
+    let neighbors = |field: &Point| {
+        let height = map.height(*field);
+        map.0
+            .neighbors4(*field)
+            .filter(move |neighbor| map.height(*neighbor) <= height + 1)
+    };
+    let path = search::bfs(start, neighbors, |field| *field == end, stats)?;
+    Some(path.len() - 1)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Map;
+    type Answer1 = MaybeAnswer<usize>;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse::<Map>()
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Self::Answer1 {
+        Self::part1_stats(map, &mut SearchStats::default())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_stats(map, &mut SearchStats::default())
+    }
+}
+
+impl Instrumented for Day12 {
+    fn part1_stats(map: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer1 {
+        shortest_path_with_stats(map, vec![map.start().unwrap()], stats).into()
+    }
+
+    fn part2_stats(map: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer2 {
+        shortest_path_with_stats(map, map.find_lowest_fields(), stats).into()
+    }
+}
@@ -0,0 +1,30 @@
+use std::iter;
+
+use common::{
+    render::{gradient, Picture, Pictures, Render, Rgb},
+    Part,
+};
+use grid::Point;
+
+use crate::{Day12, Map};
+
+/// A single picture of the elevation from `a` to `z`, with the start and the end in colors of their own. It is the
+/// same for both parts.
+impl Render for Day12 {
+    fn palette() -> Vec<Rgb> {
+        let mut palette = gradient([0x20, 0x40, 0x20], [0xf0, 0xf0, 0xe8], 26);
+        palette.extend([[0x30, 0x70, 0xf0], [0xf0, 0x30, 0x30]]);
+        palette
+    }
+
+    fn pictures<'a>(map: &'a Self::Input<'_>, _part: Part) -> Pictures<'a> {
+        let Map(grid) = map;
+        let max = (grid.width() as i64 - 1, grid.height() as i64 - 1);
+        let picture = Picture::from_fn((0, 0), max, |x, y| match grid[Point::new(x, y)] {
+            b'S' => 26,
+            b'E' => 27,
+            height => height - b'a',
+        });
+        Box::new(iter::once(picture))
+    }
+}
@@ -0,0 +1,35 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::{Day13, Entry};
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let entries = (0..rng.random_range(0..=4))
+        .map(|_| match depth < 4 && rng.random_bool(0.3) {
+            true => packet(rng, depth + 1),
+            false => rng.random_range(0..=10).to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", entries.join(","))
+}
+
+/// A packet that is not equal to one of the divider packets, which are only added in part 2.
+fn distinct_packet(rng: &mut Rng) -> String {
+    let dividers = ["[[2]]", "[[6]]"].map(|divider| divider.parse::<Entry>().unwrap());
+    loop {
+        let packet = packet(rng, 0);
+        if !dividers.contains(&packet.parse().unwrap()) {
+            return packet;
+        }
+    }
+}
+
+/// `size` is the number of pairs of packets.
+impl Generator for Day13 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{}\n{}", distinct_packet(rng), distinct_packet(rng)))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
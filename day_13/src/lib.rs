@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use common::{
+    parse::{self, blocks, keyword, line_break, number, separated, Failure, Parsed},
+    ParseError, Solution,
+};
+
+mod generator;
+
+#[derive(Clone, Debug)]
+pub enum Entry {
+    Number(i8),
+    List(Vec<Entry>),
+}
+
+/// A list of entries in brackets, which may be empty.
+fn list(text: &str) -> Parsed<'_, Vec<Entry>> {
+    let (_, text) = keyword(text, "[")?;
+    if let Ok((_, rest)) = keyword(text, "]") {
+        return Ok((vec![], rest));
+    }
+    let (entries, text) = separated(text, ",", entry)?;
+    let (_, rest) = keyword(text, "]").map_err(|_| Failure::before(text, "',' or ']'"))?;
+    Ok((entries, rest))
+}
+
+fn entry(text: &str) -> Parsed<'_, Entry> {
+    match list(text) {
+        Ok((list, rest)) => Ok((Entry::List(list), rest)),
+        Err(_) if !text.starts_with('[') => {
+            number(text, "'[' or a number").map(|(number, rest)| (Entry::Number(number), rest))
+        }
+        Err(failure) => Err(failure),
+    }
+}
+
+fn packet(text: &str) -> Parsed<'_, Entry> {
+    list(text).map(|(list, rest)| (Entry::List(list), rest))
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, packet)
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Entry::Number(left), Entry::Number(right)) => left == right,
+            (Entry::Number(_), Entry::List(_)) => Self::List(vec![self.clone()]) == *other,
+            (Entry::List(_), Entry::Number(_)) => *self == Self::List(vec![other.clone()]),
+            (Entry::List(left), Entry::List(right)) => {
+                let mut l = left.iter();
+                let mut r = right.iter();
+                loop {
+                    match (l.next(), r.next()) {
+                        (None, None) => return true,
+                        (None, Some(_)) => return false,
+                        (Some(_), None) => return false,
+                        (Some(x), Some(y)) if x == y => (),
+                        (Some(_), Some(_)) => return false,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // println!("self: {:?}, other: {:?}", self, other);
+        match (self, other) {
+            (Entry::Number(left), Entry::Number(right)) => left.cmp(right),
+            (Entry::Number(_), Entry::List(_)) => Self::List(vec![self.clone()]).cmp(other),
+            (Entry::List(_), Entry::Number(_)) => self.cmp(&Self::List(vec![other.clone()])),
+            (Entry::List(left), Entry::List(right)) => {
+                let mut l = left.iter();
+                let mut r = right.iter();
+                loop {
+                    match (l.next(), r.next()) {
+                        (None, None) => return std::cmp::Ordering::Equal,
+                        (None, Some(_)) => return std::cmp::Ordering::Less,
+                        (Some(_), None) => return std::cmp::Ordering::Greater,
+                        (Some(x), Some(y)) => {
+                            let ordering = x.partial_cmp(y).unwrap();
+                            if ordering != std::cmp::Ordering::Equal {
+                                return ordering;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Eq for Entry {}
+
+pub struct Pair {
+    pub left: Entry,
+    pub right: Entry,
+}
+
+fn pair(text: &str) -> Parsed<'_, Pair> {
+    let (left, text) = packet(text)?;
+    let (_, text) = line_break(text)?;
+    let (right, rest) = packet(text)?;
+    Ok((Pair { left, right }, rest))
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, pair)
+    }
+}
+
+impl Pair {
+    pub fn is_valid(&self) -> bool {
+        self.left < self.right
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| blocks(text, pair))
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Self::Answer1 {
+        let valids = pairs.iter().enumerate().filter(|(_, pair)| pair.is_valid());
+
+        valids.map(|(index, _)| index + 1).sum::<usize>()
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Self::Answer2 {
+        let mut lines = pairs
+            .iter()
+            .flat_map(|pair| [pair.left.clone(), pair.right.clone()])
+            .collect::<Vec<_>>();
+
+        let divider_packet_1 = "[[2]]".parse::<Entry>().unwrap();
+        let divider_packet_2 = "[[6]]".parse::<Entry>().unwrap();
+        lines.push(divider_packet_1.clone());
+        lines.push(divider_packet_2.clone());
+
+        lines.sort();
+
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| **line == divider_packet_1 || **line == divider_packet_2)
+            .map(|(line_number, _)| line_number + 1)
+            .product::<usize>()
+    }
+}
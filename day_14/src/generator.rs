@@ -0,0 +1,29 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day14;
+
+/// `size` is the number of rock paths. They spread out below the source of the sand as their number grows.
+impl Generator for Day14 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let spread = 10 + size as i64;
+        (0..size.max(1))
+            .map(|_| {
+                let mut x = 500 + rng.random_range(-spread..=spread);
+                let mut y = rng.random_range(2..=2 + spread);
+                let mut points = vec![format!("{x},{y}")];
+                for segment in 0..rng.random_range(1..=4) {
+                    let length =
+                        rng.random_range(1..=8) * if rng.random_bool(0.5) { 1 } else { -1 };
+                    match segment % 2 {
+                        0 => x += length,
+                        _ => y = (y + length).max(2),
+                    }
+                    points.push(format!("{x},{y}"));
+                }
+                points.join(" -> ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
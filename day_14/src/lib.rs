@@ -0,0 +1,202 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use automaton::{Automaton, Rules, Tick};
+use common::{
+    parse::{self, keyword, lines, number, separated, Parsed},
+    ParseError, Solution,
+};
+use geometry::Direction;
+use grid::{Point, SparseGrid};
+use itertools::Itertools;
+
+mod generator;
+mod render;
+mod simulation;
+
+fn point(text: &str) -> Parsed<'_, Point> {
+    let (x, text) = number(text, "a number")?;
+    let (_, text) = keyword(text, ",")?;
+    let (y, rest) = number(text, "a number")?;
+    Ok((Point::new(x, y), rest))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Unit {
+    Rock,
+    Air,
+    Sand,
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rock => write!(f, "#"),
+            Self::Air => write!(f, "."),
+            Self::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    fields: SparseGrid<Unit>,
+    lowest_rock: i64,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.fields)
+    }
+}
+
+impl Index<Point> for Map {
+    type Output = Unit;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.fields.get(index).unwrap_or(&Unit::Air)
+    }
+}
+
+impl IndexMut<Point> for Map {
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        self.fields.entry(index).or_insert(Unit::Air)
+    }
+}
+
+impl Map {
+    pub fn new(wall_units: &[Point]) -> Self {
+        let fields = wall_units
+            .iter()
+            .map(|point| (*point, Unit::Rock))
+            .collect::<SparseGrid<_>>();
+        let (_, max) = fields.bounds().unwrap();
+        Self {
+            fields,
+            lowest_rock: max.y,
+        }
+    }
+
+    /// Drops a unit of sand from the source. Returns where it came to rest, or `None` if it fell into the abyss below
+    /// the lowest wall. With a floor, it can not fall into the abyss.
+    pub fn drop_sand(&mut self, floor: bool) -> Option<Point> {
+        let mut current = Point::new(500, 0);
+        loop {
+            let down = current + Direction::Down;
+            let left = down + Direction::Left;
+            let right = down + Direction::Right;
+            if self[down] == Unit::Air {
+                current = down;
+            } else if self[left] == Unit::Air {
+                current = left;
+            } else if self[right] == Unit::Air {
+                current = right;
+            } else {
+                self[current] = Unit::Sand;
+                return Some(current);
+            }
+            if current.y == self.lowest_rock + 1 {
+                if !floor {
+                    return None;
+                }
+                self[current] = Unit::Sand;
+                return Some(current);
+            }
+        }
+    }
+}
+
+fn parse_walls(input: &str) -> Result<Vec<Point>, ParseError> {
+    let walls = parse::complete(input, |text| {
+        lines(text, |line| separated(line, " -> ", point))
+    })?;
+
+    let units = walls
+        .iter()
+        .flat_map(|wall| {
+            wall.as_slice().windows(2).flat_map(|wall_part| {
+                let start = wall_part[0];
+                let end = wall_part[1];
+
+                let x_range = match start.x <= end.x {
+                    true => start.x..=end.x,
+                    false => end.x..=start.x,
+                };
+                let y_range = match start.y <= end.y {
+                    true => start.y..=end.y,
+                    false => end.y..=start.y,
+                };
+
+                x_range
+                    .cartesian_product(y_range)
+                    .map(|(x, y)| Point::new(x, y))
+            })
+        })
+        .collect::<Vec<_>>();
+    match units.is_empty() {
+        true => Err(ParseError::end(
+            input,
+            "a path of rock from one point to another",
+        )),
+        false => Ok(units),
+    }
+}
+
+/// Sand falling from the source, one unit per tick. With a floor, the sand piles up until it blocks the source and
+/// nothing changes anymore. Without one, the rules stop once a unit falls into the abyss below the lowest wall.
+struct Pouring {
+    floor: bool,
+}
+
+impl Rules for Pouring {
+    type State = Map;
+
+    fn tick(&self, map: &mut Map) -> Tick {
+        if map[Point::new(500, 0)] == Unit::Sand {
+            return Tick::Unchanged;
+        }
+        match map.drop_sand(self.floor) {
+            Some(_) => Tick::Changed,
+            None => Tick::Stopped,
+        }
+    }
+}
+
+/// Drops sand until it either falls into the abyss below the lowest wall or, if there is a floor, until the source is
+/// blocked. Returns the number of sand units that came to rest.
+pub fn pour_sand(map: Map, floor: bool) -> usize {
+    let mut pouring = Automaton::new(Pouring { floor }, map);
+    pouring.fixpoint();
+    // The last tick did not let any sand come to rest
+    pouring.ticks() - 1
+}
+
+/// The map before any sand is poured and after every unit of sand that came to rest, as seen through `view`. Pouring
+/// stops like in [`pour_sand`].
+fn poured<T>(map: Map, floor: bool, view: impl FnMut(&Map) -> T) -> impl Iterator<Item = T> {
+    Automaton::new(Pouring { floor }, map).states(view)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(Map::new(&parse_walls(input)?))
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Self::Answer1 {
+        pour_sand(map.clone(), false)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Self::Answer2 {
+        pour_sand(map.clone(), true)
+    }
+}
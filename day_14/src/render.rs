@@ -0,0 +1,31 @@
+use common::{
+    render::{Picture, Pictures, Render, Rgb},
+    Part,
+};
+use grid::Point;
+
+use crate::{poured, Day14, Map, Unit};
+
+impl Map {
+    fn picture(&self) -> Picture {
+        let (min, max) = self.fields.bounds().unwrap();
+        Picture::from_fn((min.x, min.y), (max.x, max.y), |x, y| {
+            match self[Point::new(x, y)] {
+                Unit::Air => 0,
+                Unit::Rock => 1,
+                Unit::Sand => 2,
+            }
+        })
+    }
+}
+
+/// One picture per unit of sand, like the frames of the simulation.
+impl Render for Day14 {
+    fn palette() -> Vec<Rgb> {
+        vec![[0x1c, 0x1c, 0x24], [0x80, 0x78, 0x70], [0xe8, 0xc0, 0x70]]
+    }
+
+    fn pictures<'a>(map: &'a Self::Input<'_>, part: Part) -> Pictures<'a> {
+        Box::new(poured(map.clone(), part == Part::Two, Map::picture))
+    }
+}
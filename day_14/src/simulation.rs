@@ -0,0 +1,13 @@
+use common::{
+    visualize::{Frames, Simulation},
+    Part,
+};
+
+use crate::{poured, Day14};
+
+/// One frame per unit of sand, until it falls into the abyss or, with the floor of part 2, blocks the source.
+impl Simulation for Day14 {
+    fn frames(map: Self::Input<'_>, part: Part) -> Frames<'_> {
+        Box::new(poured(map, part == Part::Two, ToString::to_string))
+    }
+}
@@ -0,0 +1,36 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day15;
+
+/// The limit of the coordinates in which the distress beacon is searched.
+const LIMIT: i64 = 4000000;
+
+/// `size` is the number of sensors, all of them within the search area of the distress beacon. Their beacons are
+/// closer than the distress beacon, which is therefore never covered.
+impl Generator for Day15 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (distress_x, distress_y) = (rng.random_range(0..=LIMIT), rng.random_range(0..=LIMIT));
+        (0..size.max(1))
+            .map(|_| {
+                // The beacon has to be closer than the distress beacon, but not at the sensor itself.
+                let (x, y, distance) = loop {
+                    let (x, y) = (rng.random_range(0..=LIMIT), rng.random_range(0..=LIMIT));
+                    let distance = x.abs_diff(distress_x) + y.abs_diff(distress_y);
+                    if distance >= 2 {
+                        break (x, y, distance);
+                    }
+                };
+                let radius = rng.random_range(1..distance) as i64;
+                let dx = rng.random_range(-radius..=radius);
+                let dy = (radius - dx.abs()) * if rng.random_bool(0.5) { 1 } else { -1 };
+                format!(
+                    "Sensor at x={x}, y={y}: closest beacon is at x={}, y={}",
+                    x + dx,
+                    y + dy
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
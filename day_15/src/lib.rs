@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+
+use common::{
+    checkpoint::{Checkpoint, Resumable},
+    config::Configurable,
+    parse::{self, keyword, lines, signed, Parsed},
+    progress::{Cancellable, Outcome, Progress},
+    MaybeAnswer, ParseError, Solution,
+};
+use geometry::Point;
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+pub type Coordinate = Point<i64>;
+
+fn coordinate(text: &str) -> Parsed<'_, Coordinate> {
+    let (_, text) = keyword(text, "x=")?;
+    let (x, text) = signed(text, "a number")?;
+    let (_, text) = keyword(text, ", y=")?;
+    let (y, rest) = signed(text, "a number")?;
+    Ok((Coordinate::new(x, y), rest))
+}
+
+fn report(text: &str) -> Parsed<'_, (Coordinate, Coordinate)> {
+    let (_, text) = keyword(text, "Sensor at ")?;
+    let (sensor, text) = coordinate(text)?;
+    let (_, text) = keyword(text, ": closest beacon is at ")?;
+    let (beacon, rest) = coordinate(text)?;
+    Ok(((sensor, beacon), rest))
+}
+
+#[derive(Clone, Copy)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Range {
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        match start <= end {
+            true => Some(Self { start, end }),
+            false => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.end - self.start + 1) as usize
+    }
+
+    pub fn contains(&self, number: i64) -> bool {
+        number >= self.start && number <= self.end
+    }
+
+    pub fn limit(&self, lower: i64, upper: i64) -> Self {
+        Self {
+            start: self.start.max(lower),
+            end: self.end.min(upper),
+        }
+    }
+}
+
+/// Disjoint ranges, merged whenever they overlap.
+#[derive(Default)]
+pub struct Ranges(Vec<Range>);
+
+impl Ranges {
+    /// The number of integers in all ranges.
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|range| range.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn merge(&self, range: &Range) -> Ranges {
+        let mut merged = false;
+        let mut new_ranges = Ranges::default();
+        for r in &self.0 {
+            if range.start > r.end || range.end < r.start {
+                new_ranges.0.push(*r);
+            } else if merged {
+                new_ranges = new_ranges.merge(r);
+            } else {
+                merged = true;
+                new_ranges
+                    .0
+                    .push(Range::new(range.start.min(r.start), range.end.max(r.end)).unwrap());
+            };
+        }
+        if !merged {
+            new_ranges.0.push(*range);
+        }
+        new_ranges
+    }
+
+    pub fn contains(&self, number: i64) -> bool {
+        self.0.iter().any(|range| range.contains(number))
+    }
+}
+
+pub struct Sensor {
+    pub position: Coordinate,
+    /// The distance to the closest beacon.
+    pub distance: u64,
+}
+
+pub struct Beacon {
+    pub position: Coordinate,
+}
+
+/// The ranges of the row that are covered by each of the sensors.
+pub fn ranges_on_row(sensors: &[Sensor], row: i64) -> impl Iterator<Item = Range> + '_ {
+    sensors.iter().filter_map(move |sensor| {
+        let remaining_x = sensor.distance as i64 - sensor.position.y.abs_diff(row) as i64;
+        Range::new(
+            sensor.position.x - remaining_x,
+            sensor.position.x + remaining_x,
+        )
+    })
+}
+
+/// The tuning frequency of the only position in the search area, with both coordinates from 0 to `max_coordinate`,
+/// which no sensor covers. The rows scanned so far are saved to the checkpoint. It is `None` if the sensors cover the
+/// whole area, or if the progress is cancelled before the position is found.
+pub fn tuning_frequency(
+    sensors: &[Sensor],
+    max_coordinate: i64,
+    checkpoint: &mut Checkpoint,
+    progress: &mut Progress,
+) -> Option<i64> {
+    let first_row = checkpoint.progress("row").unwrap_or(0);
+    for row in first_row..=max_coordinate {
+        checkpoint.update("row", || row);
+        progress.report(row as f64 / (max_coordinate + 1) as f64);
+        if progress.is_cancelled() {
+            return None;
+        }
+        let ranges_on_row = ranges_on_row(sensors, row).fold(Ranges::default(), |ranges, range| {
+            ranges.merge(&range.limit(0, max_coordinate))
+        });
+
+        if ranges_on_row.len() != max_coordinate as usize + 1 {
+            let x = (0..=max_coordinate)
+                .find(|number| !ranges_on_row.contains(*number))
+                .unwrap();
+            return Some(x * 4000000 + row);
+        }
+    }
+    None
+}
+
+/// The area the sensors are looked at, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The row of part 1.
+    pub row: i64,
+    /// The largest coordinate of the search area of part 2.
+    pub max_coordinate: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            max_coordinate: 4000000,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = (Vec<Sensor>, Vec<Beacon>);
+    type Answer1 = usize;
+    type Answer2 = MaybeAnswer<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse::complete(input, |text| lines(text, report))?
+            .into_iter()
+            .map(|(sensor_coordinate, beacon_coordinate)| {
+                let distance = sensor_coordinate.manhattan_distance(beacon_coordinate);
+                (
+                    Sensor {
+                        position: sensor_coordinate,
+                        distance,
+                    },
+                    Beacon {
+                        position: beacon_coordinate,
+                    },
+                )
+            })
+            .unzip())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        Self::part1_with(input, &Params::default())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(input, &Params::default())
+    }
+}
+
+impl Configurable for Day15 {
+    type Params = Params;
+
+    fn check(params: &Params) -> Result<(), String> {
+        match params.max_coordinate >= 0 {
+            true => Ok(()),
+            false => Err("the search area needs a max_coordinate of at least 0".to_owned()),
+        }
+    }
+
+    fn part1_with((sensors, beacons): &Self::Input<'_>, params: &Params) -> Self::Answer1 {
+        let row = params.row;
+        let beacons_on_row = beacons
+            .iter()
+            .map(|beacon| beacon.position.y)
+            .filter(|y_coordinate| y_coordinate == &row)
+            .collect::<HashSet<_>>()
+            .len();
+        let ranges_on_row = ranges_on_row(sensors, row)
+            .fold(Ranges::default(), |ranges, range| ranges.merge(&range));
+
+        ranges_on_row.len() - beacons_on_row
+    }
+
+    fn part2_with((sensors, _): &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        let checkpoint = &mut Checkpoint::disabled();
+        let progress = &mut Progress::ignored();
+        tuning_frequency(sensors, params.max_coordinate, checkpoint, progress).into()
+    }
+}
+
+impl Resumable for Day15 {
+    fn part1_resumable(
+        input: &Self::Input<'_>,
+        params: &Params,
+        _: &mut Checkpoint,
+    ) -> Self::Answer1 {
+        Self::part1_with(input, params)
+    }
+
+    fn part2_resumable(
+        (sensors, _): &Self::Input<'_>,
+        params: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer2 {
+        let progress = &mut Progress::ignored();
+        tuning_frequency(sensors, params.max_coordinate, checkpoint, progress).into()
+    }
+}
+
+impl Cancellable for Day15 {
+    fn part1_cancellable(
+        input: &Self::Input<'_>,
+        params: &Params,
+        _: &mut Progress,
+    ) -> Outcome<Self::Answer1> {
+        Outcome::Finished(Self::part1_with(input, params))
+    }
+
+    fn part2_cancellable(
+        (sensors, _): &Self::Input<'_>,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer2> {
+        let checkpoint = &mut Checkpoint::disabled();
+        match tuning_frequency(sensors, params.max_coordinate, checkpoint, progress) {
+            None if progress.is_cancelled() => Outcome::Cancelled(None),
+            frequency => Outcome::Finished(frequency.into()),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Day16;
+
+/// `size` is the number of valves, from 2 to the 676 possible names. Like in the puzzle, about a quarter of them have a
+/// flow rate and most are connected by long corridors: the tunnels form a tree, with a few shortcuts.
+impl Generator for Day16 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = ('A'..='Z')
+            .flat_map(|first| ('A'..='Z').map(move |second| format!("{first}{second}")))
+            .filter(|name| name != "AA")
+            .collect::<Vec<_>>();
+        names.shuffle(rng);
+        names.truncate(size.clamp(2, 676) - 1);
+        names.insert(0, "AA".to_owned());
+
+        let mut tunnels = vec![Vec::new(); names.len()];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..names.len() {
+            connect(valve, rng.random_range(0..valve));
+        }
+        for _ in 0..names.len() / 10 {
+            connect(
+                rng.random_range(0..names.len()),
+                rng.random_range(0..names.len()),
+            );
+        }
+
+        let mut lines = names
+            .iter()
+            .zip(&tunnels)
+            .enumerate()
+            .map(|(valve, (name, tunnels))| {
+                let flow_rate = match valve > 0 && rng.random_bool(0.25) {
+                    true => rng.random_range(1..=25),
+                    false => 0,
+                };
+                let neighbors = tunnels
+                    .iter()
+                    .map(|neighbor| names[*neighbor].as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match tunnels.len() {
+                    1 => format!("Valve {name} has flow rate={flow_rate}; tunnel leads to valve {neighbors}"),
+                    _ => format!("Valve {name} has flow rate={flow_rate}; tunnels lead to valves {neighbors}"),
+                }
+            })
+            .collect::<Vec<_>>();
+        lines.shuffle(rng);
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+
+use common::{
+    checkpoint::{Checkpoint, Resumable},
+    config::Configurable,
+    parse::{self, keyword, lines, number, separated, take_while, Failure, Parsed},
+    progress::{Cancellable, Outcome, Progress},
+    stats::Instrumented,
+    ParseError, Solution,
+};
+use search::SearchStats;
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+#[derive(Clone)]
+pub struct Valve<'a> {
+    pub flow_rate: u8,
+    pub neighbors: Vec<&'a str>,
+}
+
+fn name(text: &str) -> Parsed<'_, &str> {
+    take_while(text, "the name of a valve", |c| c.is_ascii_uppercase())
+}
+
+impl<'a> Valve<'a> {
+    fn parse(text: &'a str) -> Parsed<'a, (&'a str, Self)> {
+        let (_, text) = keyword(text, "Valve ")?;
+        let (name, text) = name(text)?;
+        let (_, text) = keyword(text, " has flow rate=")?;
+        let (flow_rate, text) = number(text, "a flow rate")?;
+        let (_, text) = keyword(text, "; tunnels lead to valves ")
+            .or_else(|_| keyword(text, "; tunnel leads to valve "))
+            .map_err(|_| Failure::before(text, "'; tunnels lead to valves '"))?;
+        let (neighbors, rest) = separated(text, ", ", self::name)?;
+
+        let valve = Self {
+            flow_rate,
+            neighbors,
+        };
+        Ok(((name, valve), rest))
+    }
+}
+
+/// Someone opening valves, standing at a valve right after opening it, or at the start.
+#[derive(Clone, Serialize, Deserialize)]
+struct Opener {
+    valve: usize,
+    time_left: u8,
+}
+
+/// Where everyone opening valves stands, and which valves they opened.
+#[derive(Clone, Serialize, Deserialize)]
+struct Position {
+    openers: Vec<Opener>,
+    /// The pressure the opened valves release until the time is up.
+    released: u16,
+    opened: Vec<bool>,
+}
+
+/// The highest pressure that can be released within `max_time` minutes, with the valves opened for it.
+pub fn find_path<'a>(valves: &HashMap<&'a str, Valve<'a>>, max_time: u8) -> (u16, Vec<&'a str>) {
+    find_path_with_stats(valves, max_time, &mut SearchStats::default())
+}
+
+/// Like [`find_path`], counting the states of the search into `stats`.
+pub fn find_path_with_stats<'a>(
+    valves: &HashMap<&'a str, Valve<'a>>,
+    max_time: u8,
+    stats: &mut SearchStats,
+) -> (u16, Vec<&'a str>) {
+    let checkpoint = &mut Checkpoint::disabled();
+    find_path_with(
+        valves,
+        max_time,
+        1,
+        stats,
+        checkpoint,
+        "",
+        &mut Progress::ignored(),
+    )
+}
+
+/// Like [`find_path_with_stats`], for the given number of openers going through the tunnels at the same time and
+/// never opening the same valve, and saving the progress of the search as `name` to the checkpoint. The valves are
+/// in the order they are opened. If the progress is cancelled, it is the best path found until then.
+fn find_path_with<'a>(
+    valves: &HashMap<&'a str, Valve<'a>>,
+    max_time: u8,
+    openers: usize,
+    stats: &mut SearchStats,
+    checkpoint: &mut Checkpoint,
+    name: &str,
+    progress: &mut Progress,
+) -> (u16, Vec<&'a str>) {
+    // Only the valves with a flow are worth going to, the start comes last
+    let mut names = valves
+        .iter()
+        .filter(|(_, valve)| valve.flow_rate > 0)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    let flow_rates = names
+        .iter()
+        .map(|name| valves[name].flow_rate as u16)
+        .collect::<Vec<_>>();
+    let start = names.len();
+    // The walks between the valves are not part of the search for the best path, so they are not counted into its stats
+    let walks = &mut SearchStats::default();
+    let distances = names
+        .iter()
+        .chain(["AA"].iter())
+        .map(|from| {
+            names
+                .iter()
+                .map(|to| {
+                    let neighbors = |room: &&'a str| valves[room].neighbors.iter().copied();
+                    let path = search::bfs([*from], neighbors, |room| room == to, walks)?;
+                    Some(path.len() as u8 - 1)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // The opener with the most time left goes to one of the closed valves and opens it, if there is enough time left
+    // for it to release any pressure. If others still have time, it can also stop and leave the rest to them.
+    let branches = |position: &Position| {
+        let (moving, opener) = position
+            .openers
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, opener)| (opener.time_left, usize::MAX - index))
+            .unwrap();
+        let mut branches = (0..start)
+            .filter(|valve| !position.opened[*valve])
+            .filter_map(|valve| {
+                let cost = distances[opener.valve][valve]? + 1;
+                let time_left = opener
+                    .time_left
+                    .checked_sub(cost)
+                    .filter(|time| *time > 0)?;
+                let mut openers = position.openers.clone();
+                openers[moving] = Opener { valve, time_left };
+                let mut opened = position.opened.clone();
+                opened[valve] = true;
+                Some(Position {
+                    openers,
+                    released: position.released + time_left as u16 * flow_rates[valve],
+                    opened,
+                })
+            })
+            .collect::<Vec<_>>();
+        let others_left = position
+            .openers
+            .iter()
+            .filter(|opener| opener.time_left > 0)
+            .count()
+            > 1;
+        if others_left {
+            let mut stopped = position.clone();
+            stopped.openers[moving].time_left = 0;
+            branches.push(stopped);
+        }
+        branches
+    };
+    // As if every closed valve was gone to and opened first, by the opener who gets there first
+    let bound = |position: &Position| {
+        let closed = (0..start).filter(|valve| !position.opened[*valve]);
+        let best_case = closed.filter_map(|valve| {
+            let time_left = position.openers.iter().filter_map(|opener| {
+                let cost = distances[opener.valve][valve]? + 1;
+                Some(opener.time_left.saturating_sub(cost))
+            });
+            Some(time_left.max()? as u16 * flow_rates[valve])
+        });
+        position.released + best_case.sum::<u16>()
+    };
+
+    let initial = Position {
+        openers: vec![
+            Opener {
+                valve: start,
+                time_left: max_time,
+            };
+            openers
+        ],
+        released: 0,
+        opened: vec![false; start],
+    };
+    let (max_released_pressure, path) = search::resumable_branch_and_bound(
+        initial,
+        branches,
+        |position| position.released,
+        bound,
+        stats,
+        checkpoint.search(name),
+        progress,
+    );
+    let opened_valves = path
+        .windows(2)
+        .filter_map(|step| {
+            (0..start).find(|valve| step[1].opened[*valve] && !step[0].opened[*valve])
+        })
+        .map(|valve| names[valve])
+        .collect();
+    (max_released_pressure, opened_valves)
+}
+
+/// The highest pressure you and the elephant release together in `max_time` minutes. If the progress is cancelled, it
+/// is the pressure of the best paths found until then.
+fn release_together(
+    valves: &HashMap<&str, Valve>,
+    max_time: u8,
+    stats: &mut SearchStats,
+    checkpoint: &mut Checkpoint,
+    progress: &mut Progress,
+) -> u16 {
+    let (max_released_pressure, _) =
+        find_path_with(valves, max_time, 2, stats, checkpoint, "together", progress);
+    max_released_pressure
+}
+
+/// The time until the volcano erupts, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The minutes of part 1.
+    pub minutes: u8,
+    /// The minutes of part 2, which are left after teaching the elephant.
+    pub minutes_together: u8,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            minutes: 30,
+            minutes_together: 26,
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = HashMap<&'a str, Valve<'a>>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let valves = parse::complete(input, |text| lines(text, Valve::parse))?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        // The names of the neighbors are slices of the input, so they can be pointed at
+        let unknown = valves
+            .values()
+            .flat_map(|valve| &valve.neighbors)
+            .find(|neighbor| !valves.contains_key(*neighbor));
+        if let Some(neighbor) = unknown {
+            return Err(ParseError::at(input, neighbor, "the name of a valve"));
+        }
+        match valves.contains_key("AA") {
+            true => Ok(valves),
+            false => Err(ParseError::end(input, "the valve AA to start at")),
+        }
+    }
+
+    fn part1(valves: &Self::Input<'_>) -> Self::Answer1 {
+        Self::part1_with(valves, &Params::default())
+    }
+
+    fn part2(valves: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(valves, &Params::default())
+    }
+}
+
+impl Configurable for Day16 {
+    type Params = Params;
+
+    /// The pressure is counted in 16 bits, which is only enough for the minutes of the puzzle.
+    fn check(params: &Params) -> Result<(), String> {
+        match params.minutes.max(params.minutes_together) <= 30 {
+            true => Ok(()),
+            false => Err("the volcano erupts within 30 minutes".to_owned()),
+        }
+    }
+
+    fn part1_with(valves: &Self::Input<'_>, params: &Params) -> Self::Answer1 {
+        let (max_released_pressure, _) =
+            find_path_with_stats(valves, params.minutes, &mut SearchStats::default());
+        max_released_pressure
+    }
+
+    fn part2_with(valves: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        let progress = &mut Progress::ignored();
+        release_together(valves, params.minutes_together, stats, checkpoint, progress)
+    }
+}
+
+impl Instrumented for Day16 {
+    fn part1_stats(valves: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer1 {
+        let (max_released_pressure, _) =
+            find_path_with_stats(valves, Params::default().minutes, stats);
+        max_released_pressure
+    }
+
+    fn part2_stats(valves: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer2 {
+        let checkpoint = &mut Checkpoint::disabled();
+        let progress = &mut Progress::ignored();
+        let max_time = Params::default().minutes_together;
+        release_together(valves, max_time, stats, checkpoint, progress)
+    }
+}
+
+impl Resumable for Day16 {
+    fn part1_resumable(
+        valves: &Self::Input<'_>,
+        params: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer1 {
+        let stats = &mut SearchStats::default();
+        let progress = &mut Progress::ignored();
+        let (max_released_pressure, _) = find_path_with(
+            valves,
+            params.minutes,
+            1,
+            stats,
+            checkpoint,
+            "you",
+            progress,
+        );
+        max_released_pressure
+    }
+
+    fn part2_resumable(
+        valves: &Self::Input<'_>,
+        params: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer2 {
+        let stats = &mut SearchStats::default();
+        let progress = &mut Progress::ignored();
+        release_together(valves, params.minutes_together, stats, checkpoint, progress)
+    }
+}
+
+impl Cancellable for Day16 {
+    fn part1_cancellable(
+        valves: &Self::Input<'_>,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer1> {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        let (max_released_pressure, _) = find_path_with(
+            valves,
+            params.minutes,
+            1,
+            stats,
+            checkpoint,
+            "you",
+            progress,
+        );
+        Outcome::of(max_released_pressure, progress)
+    }
+
+    fn part2_cancellable(
+        valves: &Self::Input<'_>,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer2> {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        let max_time = params.minutes_together;
+        let max_released_pressure = release_together(valves, max_time, stats, checkpoint, progress);
+        Outcome::of(max_released_pressure, progress)
+    }
+}
@@ -0,0 +1,159 @@
+//! These properties compare `find_path` and part 2 with an exhaustive search on small generated tunnels.
+
+use std::collections::{HashMap, VecDeque};
+
+use common::Solution;
+use day_16::{find_path, Day16, Valve};
+use proptest::{prelude::*, sample::Index};
+
+/// The valves worth opening, with the travel times between them (and from "AA") precomputed.
+struct Graph {
+    flow_rates: Vec<u16>,
+    distances: Vec<Vec<u8>>,
+    start: usize,
+}
+
+impl Graph {
+    fn new(valves: &HashMap<&str, Valve>) -> Self {
+        let mut names = valves
+            .iter()
+            .filter(|(_, valve)| valve.flow_rate > 0)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.push("AA");
+
+        let distances = names
+            .iter()
+            .map(|from| {
+                let mut distance = HashMap::from([(*from, 0_u8)]);
+                let mut queue = VecDeque::from([*from]);
+                while let Some(room) = queue.pop_front() {
+                    for neighbor in &valves[room].neighbors {
+                        if !distance.contains_key(neighbor) {
+                            distance.insert(neighbor, distance[room] + 1);
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+                names.iter().map(|to| distance[to]).collect()
+            })
+            .collect();
+
+        Self {
+            flow_rates: names
+                .iter()
+                .map(|name| valves[name].flow_rate as u16)
+                .collect(),
+            distances,
+            start: names.len() - 1,
+        }
+    }
+
+    /// The best released pressure for every set of opened valves, indexed by the bitmask of the set.
+    fn best_per_opened_set(&self, max_time: u8) -> Vec<u16> {
+        let mut best = vec![0; 1 << self.start];
+        self.visit(self.start, max_time, 0, 0, &mut best);
+        best
+    }
+
+    fn visit(&self, room: usize, time_left: u8, opened: usize, released: u16, best: &mut [u16]) {
+        best[opened] = best[opened].max(released);
+        for valve in (0..self.start).filter(|valve| opened & (1 << valve) == 0) {
+            let cost = self.distances[room][valve] + 1;
+            if cost < time_left {
+                let time_left = time_left - cost;
+                let released = released + time_left as u16 * self.flow_rates[valve];
+                self.visit(valve, time_left, opened | (1 << valve), released, best);
+            }
+        }
+    }
+}
+
+fn reference_alone(valves: &HashMap<&str, Valve>, max_time: u8) -> u16 {
+    let best = Graph::new(valves).best_per_opened_set(max_time);
+    best.into_iter().max().unwrap()
+}
+
+fn reference_with_elephant(valves: &HashMap<&str, Valve>) -> u16 {
+    let best = Graph::new(valves).best_per_opened_set(26);
+    (0..best.len())
+        .flat_map(|mine| {
+            (0..best.len())
+                .filter(move |theirs| mine & theirs == 0)
+                .map(move |theirs| (mine, theirs))
+        })
+        .map(|(mine, theirs)| best[mine] + best[theirs])
+        .max()
+        .unwrap()
+}
+
+/// The name of the valve with the index, "AA" for the first one.
+fn name(valve: usize) -> String {
+    [valve / 26, valve % 26]
+        .map(|letter| char::from(b'A' + letter as u8))
+        .iter()
+        .collect()
+}
+
+/// Tunnels forming a tree like in the puzzle, with a few shortcuts. Every valve after "AA" has a flow rate, which is
+/// mostly 0, and is connected to one of the valves before it. These are shrunk instead of the text, so a failure is
+/// reported with the fewest valves and tunnels and the lowest flow rates.
+fn tunnels() -> impl Strategy<Value = String> {
+    let flow_rate = prop_oneof![3 => Just(0), 1 => 1..=25_u8];
+    let valves = prop::collection::vec((flow_rate, any::<Index>()), 1..24);
+    let shortcuts = prop::collection::vec((any::<Index>(), any::<Index>()), 0..3);
+    (valves, shortcuts).prop_map(|(valves, shortcuts)| {
+        let count = valves.len() + 1;
+        let mut flow_rates = vec![0];
+        let mut tunnels = vec![vec![]; count];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for (valve, (flow_rate, before)) in (1..).zip(valves) {
+            flow_rates.push(flow_rate);
+            connect(valve, before.index(valve));
+        }
+        for (a, b) in shortcuts {
+            connect(a.index(count), b.index(count));
+        }
+        (0..count)
+            .map(|valve| {
+                let neighbors = tunnels[valve].iter().map(|neighbor| name(*neighbor));
+                format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {}",
+                    name(valve),
+                    flow_rates[valve],
+                    neighbors.collect::<Vec<_>>().join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+#[test]
+fn reference_matches_the_example() {
+    let valves = Day16::parse(include_str!("../data/demo_input.txt")).unwrap();
+    assert_eq!(reference_alone(&valves, 30), 1651);
+    assert_eq!(reference_with_elephant(&valves), 1707);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 64, failure_persistence: None, ..ProptestConfig::default() })]
+
+    #[test]
+    fn find_path_releases_the_most_pressure(input in tunnels(), max_time in 1..=30_u8) {
+        let valves = Day16::parse(&input).unwrap();
+        prop_assert_eq!(find_path(&valves, max_time).0, reference_alone(&valves, max_time), "input:\n{}", input);
+    }
+
+    #[test]
+    fn part2_releases_the_most_pressure(input in tunnels()) {
+        let valves = Day16::parse(&input).unwrap();
+        prop_assert_eq!(Day16::part2(&valves), reference_with_elephant(&valves), "input:\n{}", input);
+    }
+}
@@ -0,0 +1,13 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day17;
+
+/// `size` is the length of the jet pattern.
+impl Generator for Day17 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+            .collect()
+    }
+}
@@ -0,0 +1,333 @@
+use std::fmt::Display;
+
+use automaton::{Automaton, Rules, Tick};
+use common::{
+    parse::{self, symbol},
+    ParseError, Solution,
+};
+use enum_iterator::{next_cycle, Sequence};
+
+mod generator;
+mod render;
+mod simulation;
+
+#[derive(Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Sequence)]
+pub enum Shape {
+    Horizontal,
+    Plus,
+    L,
+    Vertical,
+    Square,
+}
+
+impl Shape {
+    fn width(&self) -> u8 {
+        match self {
+            Shape::Horizontal => 4,
+            Shape::Plus => 3,
+            Shape::L => 3,
+            Shape::Vertical => 1,
+            Shape::Square => 2,
+        }
+    }
+
+    fn coordinates(&self) -> Vec<(u8, u8)> {
+        match self {
+            Shape::Horizontal => vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            Shape::Plus => vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            Shape::L => vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Shape::Vertical => vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            Shape::Square => vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        }
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Shape::Horizontal => 'H',
+            Shape::Plus => '+',
+            Shape::L => 'L',
+            Shape::Vertical => 'v',
+            Shape::Square => 'x',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rock {
+    shape: Shape,
+    x: usize,
+    y: usize,
+}
+
+impl Rock {
+    fn new(shape: Shape, y: usize) -> Self {
+        Self {
+            shape,
+            x: 3,
+            y: y + 4,
+        }
+    }
+
+    fn push(&self, direction: Direction) -> Self {
+        let x = match direction {
+            Direction::Left => self.x - 1,
+            Direction::Right => self.x + 1,
+        };
+
+        Self { x, ..*self }
+    }
+
+    fn down(&self) -> Self {
+        let y = self.y - 1;
+        Self { y, ..*self }
+    }
+
+    fn is_valid(&self, chamber: &Chamber) -> bool {
+        if self.y == 0 || self.x == 0 || self.x + self.shape.width() as usize > 8 {
+            return false;
+        }
+        self.shape
+            .coordinates()
+            .iter()
+            .map(|(piece_x, piece_y)| (*piece_x as usize + self.x, *piece_y as usize + self.y))
+            .all(|(x, y)| !chamber.is_occupied(x, y))
+    }
+}
+
+#[derive(Debug)]
+pub struct Chamber(Vec<[Option<Shape>; 7]>);
+
+impl Chamber {
+    pub fn is_occupied(&self, x: usize, y: usize) -> bool {
+        if y > self.height() {
+            return false;
+        }
+        self.0[y - 1][x - 1].is_some()
+    }
+
+    fn add(&mut self, rock: &Rock) {
+        rock.shape
+            .coordinates()
+            .iter()
+            .map(|(x, y)| (*x as usize + rock.x, *y as usize + rock.y))
+            .for_each(|(x, y)| {
+                if y > self.height() {
+                    self.0.resize(y, [None; 7])
+                }
+                self.0[y - 1][x - 1] = Some(rock.shape);
+            });
+    }
+    pub fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Lets a rock of the shape fall, pushed around by the jets, until it comes to rest.
+    pub fn drop_rock(&mut self, shape: Shape, jets: &mut impl Iterator<Item = Direction>) {
+        let mut rock = Rock::new(shape, self.height());
+        loop {
+            let next_rock = rock.push(jets.next().unwrap());
+            if next_rock.is_valid(self) {
+                rock = next_rock;
+            }
+            let next_rock = rock.down();
+            if next_rock.is_valid(self) {
+                rock = next_rock;
+            } else {
+                self.add(&rock);
+                break;
+            }
+        }
+    }
+}
+
+/// Renders the chamber like in the puzzle, with the top row first and the floor last.
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.0.iter().rev() {
+            write!(f, "|")?;
+            for field in row {
+                match field {
+                    Some(s) => write!(f, "{}", s)?,
+                    None => write!(f, " ")?,
+                };
+            }
+            writeln!(f, "|")?;
+        }
+        writeln!(f, "+-------+")
+    }
+}
+
+/// The rocks which came to rest so far, and where the shapes and the jets go on.
+struct Tower {
+    chamber: Chamber,
+    shape: Shape,
+    jet: usize,
+}
+
+impl Tower {
+    fn new() -> Self {
+        Self {
+            chamber: Chamber(vec![]),
+            shape: Shape::Horizontal,
+            jet: 0,
+        }
+    }
+
+    /// What makes the tower grow the same way again: the next shape and jet, and the free fields which can still be
+    /// reached from above, as bits of the rows from the top down. Rocks only move sideways and down, so none of them
+    /// ever gets to the other fields. A column which no rock fills stays reachable down to the floor, so the surface
+    /// ends after `rows` rows at the latest.
+    fn key(&self, rows: usize) -> (Shape, usize, Vec<u8>) {
+        let mut surface = vec![];
+        let mut above = 0b111_1111;
+        for row in self.chamber.0.iter().rev().take(rows) {
+            let free = row
+                .iter()
+                .fold(0, |bits, field| bits << 1 | field.is_none() as u8);
+            let mut reached = above & free;
+            loop {
+                let spread = (reached | reached << 1 | reached >> 1) & free;
+                if spread == reached {
+                    break;
+                }
+                reached = spread;
+            }
+            if reached == 0 {
+                break;
+            }
+            surface.push(reached);
+            above = reached;
+        }
+        (self.shape, self.jet, surface)
+    }
+}
+
+/// One rock per tick falls into the chamber, pushed around by the jets.
+struct Falling {
+    jets: Vec<Direction>,
+}
+
+impl Rules for Falling {
+    type State = Tower;
+
+    fn tick(&self, tower: &mut Tower) -> Tick {
+        let mut used_jets = 0;
+        let mut jets = self.jets[tower.jet..]
+            .iter()
+            .chain(self.jets.iter().cycle())
+            .inspect(|_| used_jets += 1)
+            .copied();
+        tower.chamber.drop_rock(tower.shape, &mut jets);
+        tower.shape = next_cycle(&tower.shape).unwrap();
+        tower.jet = (tower.jet + used_jets) % self.jets.len();
+        Tick::Changed
+    }
+}
+
+/// The chamber after the given number of rocks came to rest.
+pub fn fallen_rocks(jets: &[Direction], rock_count: usize) -> Chamber {
+    let mut falling = Automaton::new(
+        Falling {
+            jets: jets.to_vec(),
+        },
+        Tower::new(),
+    );
+    falling.run(rock_count);
+    falling.into_state().chamber
+}
+
+/// The empty chamber and the chamber after every rock that came to rest, as seen through `view`.
+fn stacked<T>(
+    jets: Vec<Direction>,
+    rock_count: usize,
+    mut view: impl FnMut(&Chamber) -> T,
+) -> impl Iterator<Item = T> {
+    Automaton::new(Falling { jets }, Tower::new())
+        .states(move |tower| view(&tower.chamber))
+        .take(rock_count.saturating_add(1))
+}
+
+/// The height of the tower after `rock_count` rocks, extrapolated from the first repetition of the falling rocks.
+///
+/// The tower is only compared down to a number of rows, and rocks can fall deeper than that, into a column which no
+/// rock fills. So the repetition has to go on for two more cycles in the simulation before the height is extrapolated
+/// from it, and otherwise the rocks are dropped again with twice as many rows compared.
+pub fn tower_height(jets: &[Direction], rock_count: usize) -> usize {
+    let mut rows = 32;
+    loop {
+        let mut heights = vec![];
+        let mut falling = Automaton::new(
+            Falling {
+                jets: jets.to_vec(),
+            },
+            Tower::new(),
+        );
+        let cycle = falling
+            .find_cycle(|tower| {
+                heights.push(tower.chamber.height());
+                tower.key(rows)
+            })
+            .expect("The rocks fall forever");
+        if rock_count < heights.len() {
+            return heights[rock_count];
+        }
+
+        let growth = heights[cycle.start + cycle.length] - heights[cycle.start];
+        let checked = cycle.start + 3 * cycle.length;
+        while heights.len() <= checked {
+            falling.tick();
+            heights.push(falling.state().chamber.height());
+        }
+        let repeats = (cycle.start..=checked - cycle.length)
+            .all(|rocks| heights[rocks + cycle.length] - heights[rocks] == growth);
+        if repeats {
+            let repetitions = (rock_count - cycle.start) / cycle.length;
+            let rest = (rock_count - cycle.start) % cycle.length;
+            return heights[cycle.start + rest] + repetitions * growth;
+        }
+        rows *= 2;
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Vec<Direction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |mut text| {
+            let mut jets = vec![];
+            while jets.is_empty() || !text.trim_end().is_empty() {
+                let (jet, rest) = symbol(text, "'<' or '>'", |c| match c {
+                    '<' => Some(Direction::Left),
+                    '>' => Some(Direction::Right),
+                    _ => None,
+                })?;
+                jets.push(jet);
+                text = rest;
+            }
+            Ok((jets, text))
+        })
+    }
+
+    fn part1(jets: &Self::Input<'_>) -> Self::Answer1 {
+        fallen_rocks(jets, 2022).height()
+    }
+
+    fn part2(jets: &Self::Input<'_>) -> Self::Answer2 {
+        tower_height(jets, 1000000000000)
+    }
+}
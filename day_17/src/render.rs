@@ -0,0 +1,37 @@
+use common::{
+    render::{Picture, Pictures, Render, Rgb},
+    Part,
+};
+
+use crate::{stacked, Chamber, Day17};
+
+impl Chamber {
+    /// The rows are placed above the floor at `y = 0`, so that the towers of all frames stand on the same ground.
+    fn picture(&self) -> Picture {
+        Picture::from_fn((0, -(self.height() as i64)), (6, -1), |x, y| {
+            match self.0[(-y - 1) as usize][x as usize] {
+                Some(shape) => shape as u8 + 1,
+                None => 0,
+            }
+        })
+    }
+}
+
+/// One picture per rock that came to rest, for the 2022 rocks of part 1. Part 2 has far too many rocks to draw, so
+/// it is drawn like part 1.
+impl Render for Day17 {
+    fn palette() -> Vec<Rgb> {
+        vec![
+            [0x10, 0x10, 0x18],
+            [0xe0, 0x50, 0x40],
+            [0xf0, 0xb0, 0x30],
+            [0x60, 0xc0, 0x50],
+            [0x40, 0x90, 0xe0],
+            [0xb0, 0x60, 0xd0],
+        ]
+    }
+
+    fn pictures<'a>(jets: &'a Self::Input<'_>, _part: Part) -> Pictures<'a> {
+        Box::new(stacked(jets.clone(), 2022, Chamber::picture))
+    }
+}
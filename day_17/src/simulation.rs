@@ -0,0 +1,17 @@
+use common::{
+    visualize::{Frames, Simulation},
+    Part,
+};
+
+use crate::{stacked, Day17};
+
+/// One frame per rock that came to rest. Part 1 stops after 2022 rocks, part 2 goes on for as long as one watches.
+impl Simulation for Day17 {
+    fn frames(jets: Self::Input<'_>, part: Part) -> Frames<'_> {
+        let rock_count = match part {
+            Part::One => 2022,
+            Part::Two => usize::MAX,
+        };
+        Box::new(stacked(jets, rock_count, ToString::to_string))
+    }
+}
@@ -0,0 +1,61 @@
+//! `tower_height` extrapolates the height from the first repetition of the falling rocks, recognized by the next shape
+//! and jet and the fields at the top which rocks can still reach. This property compares it with the plain simulation
+//! on small generated jet patterns, for as many rocks as can still be simulated.
+
+use common::Solution;
+use day_17::{fallen_rocks, tower_height, Day17};
+use proptest::prelude::*;
+
+/// Jet patterns, which are shrunk to fewer jets and to jets pushing left.
+fn jets() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop_oneof![Just('<'), Just('>')], 1..=200)
+        .prop_map(|jets| jets.into_iter().collect())
+}
+
+#[test]
+fn tower_height_matches_the_simulation_for_the_example() {
+    let jets = Day17::parse(include_str!("../data/demo_input.txt")).unwrap();
+    for rock_count in [2022, 3333, 5000] {
+        assert_eq!(
+            tower_height(&jets, rock_count),
+            fallen_rocks(&jets, rock_count).height()
+        );
+    }
+}
+
+/// No rock ever fills the right column with the first jets, and with the second ones a rock falls to the bottom of a
+/// column which gets deeper with every cycle.
+#[test]
+fn tower_height_matches_the_simulation_with_a_column_no_rock_fills() {
+    for input in ["<<><<><\n", "><><><>>><<>\n"] {
+        let jets = Day17::parse(input).unwrap();
+        for rock_count in [2022, 5000] {
+            assert_eq!(
+                tower_height(&jets, rock_count),
+                fallen_rocks(&jets, rock_count).height()
+            );
+        }
+    }
+}
+
+proptest! {
+    // Every case runs in a process of its own which is stopped after 10 seconds, so a missed cycle fails the case
+    // instead of hanging the tests.
+    #![proptest_config(ProptestConfig {
+        cases: 64,
+        timeout: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn tower_height_matches_the_simulation(input in jets(), rock_count in 2022..=5000_usize) {
+        let jets = Day17::parse(&input).unwrap();
+        prop_assert_eq!(
+            tower_height(&jets, rock_count),
+            fallen_rocks(&jets, rock_count).height(),
+            "input:\n{}",
+            input
+        );
+    }
+}
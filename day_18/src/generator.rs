@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use common::generate::{Generator, Rng};
+use geometry::Point3;
+use rand::{seq::IndexedRandom, Rng as _};
+
+use crate::{Cube, Day18};
+
+/// `size` is the number of cubes of the droplet. It grows from a single cube by adding neighbors of random cubes, which
+/// leaves some air pockets inside. All coordinates are positive, like in the puzzle.
+impl Generator for Day18 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let center = (size as f64).cbrt() as i32 + 1;
+        let mut cubes = vec![Cube::new(center, center, center)];
+        let mut known = cubes.iter().copied().collect::<HashSet<_>>();
+        while cubes.len() < size {
+            let cube = *cubes.choose(rng).unwrap();
+            let neighbor = cube.neighbors6().nth(rng.random_range(0..6)).unwrap();
+            if neighbor.x >= 0 && neighbor.y >= 0 && neighbor.z >= 0 && known.insert(neighbor) {
+                cubes.push(neighbor);
+            }
+        }
+        cubes
+            .iter()
+            .map(|Point3 { x, y, z }| format!("{x},{y},{z}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, keyword, lines, signed, Parsed},
+    ParseError, Solution,
+};
+use geometry::Point3;
+use itertools::Itertools;
+
+mod generator;
+
+pub type Cube = Point3<i32>;
+
+fn cube(text: &str) -> Parsed<'_, Cube> {
+    let (x, text) = signed(text, "a coordinate")?;
+    let (_, text) = keyword(text, ",")?;
+    let (y, text) = signed(text, "a coordinate")?;
+    let (_, text) = keyword(text, ",")?;
+    let (z, rest) = signed(text, "a coordinate")?;
+    Ok((Cube::new(x, y, z), rest))
+}
+
+#[derive(PartialEq)]
+enum Content {
+    Lava,
+    Water,
+    Unknown,
+}
+struct Vessel {
+    map: HashMap<Cube, Content>,
+    first: Cube,
+}
+
+impl Vessel {
+    fn new_for_lava(cubes: &[Cube]) -> Self {
+        // +/-1, to allow some water to flow around the lava
+        let x_min = cubes.iter().min_by(|a, b| a.x.cmp(&b.x)).unwrap().x - 1;
+        let y_min = cubes.iter().min_by(|a, b| a.y.cmp(&b.y)).unwrap().y - 1;
+        let z_min = cubes.iter().min_by(|a, b| a.z.cmp(&b.z)).unwrap().z - 1;
+        let x_max = cubes.iter().max_by(|a, b| a.x.cmp(&b.x)).unwrap().x + 1;
+        let y_max = cubes.iter().max_by(|a, b| a.y.cmp(&b.y)).unwrap().y + 1;
+        let z_max = cubes.iter().max_by(|a, b| a.z.cmp(&b.z)).unwrap().z + 1;
+
+        // This one is guaranteed to be empty and will be filled with water
+        let first = Cube::new(x_min, y_min, z_min);
+
+        // Create empty map with all cubes set to unknown
+        let mut map = [x_min..=x_max, y_min..=y_max, z_min..=z_max]
+            .into_iter()
+            .multi_cartesian_product()
+            .map(|v| {
+                let mut v = v.iter();
+                let x = v.next().unwrap();
+                let y = v.next().unwrap();
+                let z = v.next().unwrap();
+                (Cube::new(*x, *y, *z), Content::Unknown)
+            })
+            .collect::<HashMap<_, _>>();
+
+        // Insert lava
+        map.extend(cubes.iter().map(|cube| (*cube, Content::Lava)));
+
+        Self { map, first }
+    }
+
+    fn fill_water(&mut self) {
+        let mut candidates = vec![self.first];
+
+        while let Some(candidate) = candidates.pop() {
+            self.map
+                .entry(candidate)
+                .and_modify(|x| *x = Content::Water);
+
+            for neighbor in candidate.neighbors6() {
+                if let Some((_, content)) = self.map.get_key_value(&neighbor) {
+                    if content == &Content::Unknown {
+                        candidates.push(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    fn air(&self) -> Vec<Cube> {
+        self.map
+            .iter()
+            .filter_map(|(cube, content)| {
+                if content == &Content::Unknown {
+                    Some(*cube)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// The number of cube sides that are not connected to another cube.
+pub fn get_total_surface(cubes: &[Cube]) -> usize {
+    let sites = cubes
+        .iter()
+        .map(|cube| {
+            cubes
+                .iter()
+                .filter(|inner_cube| inner_cube.manhattan_distance(*cube) == 1)
+                .count()
+        })
+        .sum::<usize>();
+    let total_sites = cubes.len() * 6;
+    total_sites - sites
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let cubes = parse::complete(input, |text| lines(text, cube))?;
+        match cubes.is_empty() {
+            true => Err(ParseError::end(input, "a cube")),
+            false => Ok(cubes),
+        }
+    }
+
+    fn part1(lava_cubes: &Self::Input<'_>) -> Self::Answer1 {
+        get_total_surface(lava_cubes)
+    }
+
+    fn part2(lava_cubes: &Self::Input<'_>) -> Self::Answer2 {
+        let total_surface = get_total_surface(lava_cubes);
+
+        let mut vessel = Vessel::new_for_lava(lava_cubes);
+        vessel.fill_water();
+
+        let air_cubes = vessel.air();
+        let air_surface = get_total_surface(&air_cubes);
+
+        total_surface - air_surface
+    }
+}
@@ -0,0 +1,24 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day19;
+
+/// `size` is the number of blueprints, with costs in the ranges of the puzzle.
+impl Generator for Day19 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|id| {
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+                    rng.random_range(2..=4),
+                    rng.random_range(2..=4),
+                    rng.random_range(2..=4),
+                    rng.random_range(5..=20),
+                    rng.random_range(2..=4),
+                    rng.random_range(5..=20),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,458 @@
+use std::str::FromStr;
+
+use common::{
+    checkpoint::{Checkpoint, Resumable},
+    config::Configurable,
+    parse::{self, keyword, lines, number, optional, Parsed},
+    progress::{Cancellable, Outcome, Progress},
+    stats::Instrumented,
+    ParseError, Solution,
+};
+use search::SearchStats;
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+#[derive(Clone, Copy)]
+pub enum Unit {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+pub struct Blueprint {
+    pub id: u32,
+    pub cost_per_ore_robot: u32,
+    pub cost_per_clay_robot: u32,
+    pub cost_per_obisidan_robot: [u32; 2],
+    pub cost_per_geode_robot: [u32; 2],
+}
+
+fn blueprint(text: &str) -> Parsed<'_, Blueprint> {
+    let mut text = optional(text, |text| keyword(text, "Blueprint "))?.1;
+    // The numbers of the blueprint, each after its part of the sentence
+    let mut numbers = [0; 7];
+    let parts = [
+        "",
+        ": Each ore robot costs ",
+        " ore. Each clay robot costs ",
+        " ore. Each obsidian robot costs ",
+        " ore and ",
+        " clay. Each geode robot costs ",
+        " ore and ",
+    ];
+    for (number, part) in numbers.iter_mut().zip(parts) {
+        text = keyword(text, part)?.1;
+        (*number, text) = self::number(text, "a number")?;
+    }
+    let (_, rest) = keyword(text, " obsidian.")?;
+
+    let [id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = numbers;
+    let blueprint = Blueprint {
+        id,
+        cost_per_ore_robot: ore,
+        cost_per_clay_robot: clay,
+        cost_per_obisidan_robot: [obsidian_ore, obsidian_clay],
+        cost_per_geode_robot: [geode_ore, geode_obsidian],
+    };
+    Ok((blueprint, rest))
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, blueprint)
+    }
+}
+
+#[derive(Default, Clone, Copy, Ord, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
+struct State {
+    time: u32,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+}
+
+impl State {
+    fn initial() -> Self {
+        Self {
+            ore_robots: 1,
+            ..Self::default()
+        }
+    }
+
+    fn time_till_robot(&self, unit: Unit, blueprint: &Blueprint) -> u32 {
+        match unit {
+            Unit::Ore => {
+                if self.ore >= blueprint.cost_per_ore_robot {
+                    0
+                } else {
+                    u32::div_ceil(
+                        blueprint.cost_per_ore_robot.saturating_sub(self.ore),
+                        self.ore_robots,
+                    )
+                }
+            }
+            Unit::Clay => {
+                if self.ore >= blueprint.cost_per_clay_robot {
+                    0
+                } else {
+                    u32::div_ceil(
+                        blueprint.cost_per_clay_robot.saturating_sub(self.ore),
+                        self.ore_robots,
+                    )
+                }
+            }
+            Unit::Obsidian => {
+                if self.ore >= blueprint.cost_per_obisidan_robot[0]
+                    && self.clay >= blueprint.cost_per_obisidan_robot[1]
+                {
+                    0
+                } else {
+                    let ore_time = u32::div_ceil(
+                        blueprint.cost_per_obisidan_robot[0].saturating_sub(self.ore),
+                        self.ore_robots,
+                    );
+                    let clay_time = u32::div_ceil(
+                        blueprint.cost_per_obisidan_robot[1].saturating_sub(self.clay),
+                        self.clay_robots,
+                    );
+                    ore_time.max(clay_time)
+                }
+            }
+            Unit::Geode => {
+                if self.ore >= blueprint.cost_per_geode_robot[0]
+                    && self.obsidian >= blueprint.cost_per_geode_robot[1]
+                {
+                    0
+                } else {
+                    let ore_time = u32::div_ceil(
+                        blueprint.cost_per_geode_robot[0].saturating_sub(self.ore),
+                        self.ore_robots,
+                    );
+                    let obsidian_time = u32::div_ceil(
+                        blueprint.cost_per_geode_robot[1].saturating_sub(self.obsidian),
+                        self.obsidian_robots,
+                    );
+                    ore_time.max(obsidian_time)
+                }
+            }
+        }
+    }
+
+    fn can_buy_robot(&self, robot: Unit) -> bool {
+        match robot {
+            Unit::Ore => self.ore_robots > 0,
+            Unit::Clay => self.ore_robots > 0,
+            Unit::Obsidian => self.ore_robots > 0 && self.clay_robots > 0,
+            Unit::Geode => self.ore_robots > 0 && self.obsidian_robots > 0,
+        }
+    }
+
+    /// Whether another robot of the kind can still help: as only one robot is built per minute, there is no use in
+    /// mining more of a unit per minute than the most expensive robot costs of it.
+    fn needs_robot(&self, robot: Unit, blueprint: &Blueprint) -> bool {
+        match robot {
+            Unit::Ore => {
+                let most_expensive = blueprint
+                    .cost_per_ore_robot
+                    .max(blueprint.cost_per_clay_robot)
+                    .max(blueprint.cost_per_obisidan_robot[0])
+                    .max(blueprint.cost_per_geode_robot[0]);
+                self.ore_robots < most_expensive
+            }
+            Unit::Clay => self.clay_robots < blueprint.cost_per_obisidan_robot[1],
+            Unit::Obsidian => self.obsidian_robots < blueprint.cost_per_geode_robot[1],
+            Unit::Geode => true,
+        }
+    }
+
+    fn buy_robot(&self, robot: Unit, blueprint: &Blueprint) -> Option<State> {
+        if !self.can_buy_robot(robot) {
+            return None;
+        }
+
+        let needed_time = self.time_till_robot(robot, blueprint) + 1;
+        let mut next_state = self.progress(needed_time);
+
+        match robot {
+            Unit::Ore => {
+                next_state.ore -= blueprint.cost_per_ore_robot;
+                next_state.ore_robots += 1;
+            }
+            Unit::Clay => {
+                next_state.ore -= blueprint.cost_per_clay_robot;
+                next_state.clay_robots += 1;
+            }
+            Unit::Obsidian => {
+                next_state.ore -= blueprint.cost_per_obisidan_robot[0];
+                next_state.clay -= blueprint.cost_per_obisidan_robot[1];
+                next_state.obsidian_robots += 1;
+            }
+            Unit::Geode => {
+                next_state.ore -= blueprint.cost_per_geode_robot[0];
+                next_state.obsidian -= blueprint.cost_per_geode_robot[1];
+                next_state.geode_robots += 1;
+            }
+        }
+
+        Some(next_state)
+    }
+
+    fn progress(&self, time: u32) -> Self {
+        Self {
+            time: self.time + time,
+            ore: self.ore + time * self.ore_robots,
+            clay: self.clay + time * self.clay_robots,
+            obsidian: self.obsidian + time * self.obsidian_robots,
+            geode: self.geode + time * self.geode_robots,
+            ..*self
+        }
+    }
+}
+
+/// The largest number of geodes that can be opened with the blueprint within `max_time` minutes.
+pub fn evaluate(blueprint: &Blueprint, max_time: u32) -> u32 {
+    evaluate_with_stats(blueprint, max_time, &mut SearchStats::default())
+}
+
+/// Like [`evaluate`], counting the states of the search into `stats`.
+pub fn evaluate_with_stats(blueprint: &Blueprint, max_time: u32, stats: &mut SearchStats) -> u32 {
+    let checkpoint = &mut Checkpoint::disabled();
+    evaluate_with(
+        blueprint,
+        max_time,
+        stats,
+        checkpoint,
+        &mut Progress::ignored(),
+    )
+}
+
+/// Like [`evaluate_with_stats`], saving the progress of the search to the checkpoint, under the id of the blueprint.
+/// If the progress is cancelled, it is the most geodes found until then.
+fn evaluate_with(
+    blueprint: &Blueprint,
+    max_time: u32,
+    stats: &mut SearchStats,
+    checkpoint: &mut Checkpoint,
+    progress: &mut Progress,
+) -> u32 {
+    // Buys one of the robots next, if it is ready while there is still time left for it to collect anything
+    let branches = |state: &State| {
+        [Unit::Geode, Unit::Obsidian, Unit::Clay, Unit::Ore]
+            .into_iter()
+            .filter(|robot| state.needs_robot(*robot, blueprint))
+            .filter_map(|robot| state.buy_robot(robot, blueprint))
+            .filter(|next_state| next_state.time < max_time)
+            .collect::<Vec<_>>()
+    };
+    // The geodes at the end, if no more robots are bought
+    let geodes = |state: &State| state.geode + (max_time - state.time) * state.geode_robots;
+    // As if a geode robot was bought every minute from now on
+    let bound = |state: &State| {
+        let time_left = max_time - state.time;
+        geodes(state) + time_left * time_left.saturating_sub(1) / 2
+    };
+
+    let name = format!("blueprint {}", blueprint.id);
+    let (max_geodes, _) = search::resumable_branch_and_bound(
+        State::initial(),
+        branches,
+        geodes,
+        bound,
+        stats,
+        checkpoint.search(&name),
+        progress,
+    );
+    max_geodes
+}
+
+/// The sum of the quality levels of all blueprints within 24 minutes. Once the progress is cancelled, the blueprints
+/// left count with the geodes found until then, which is none for the ones not started.
+fn quality_level_sum(
+    blueprints: &[Blueprint],
+    stats: &mut SearchStats,
+    checkpoint: &mut Checkpoint,
+    progress: &mut Progress,
+) -> u32 {
+    let count = blueprints.len();
+    blueprints
+        .iter()
+        .enumerate()
+        .map(|(index, blueprint)| {
+            let progress = &mut progress.section(index, count);
+            blueprint.id * evaluate_with(blueprint, 24, stats, checkpoint, progress)
+        })
+        .sum()
+}
+
+/// The product of the most geodes of the first blueprints within the time of part 2. Like [`quality_level_sum`] once
+/// the progress is cancelled.
+fn max_geodes_product(
+    blueprints: &[Blueprint],
+    params: &Params,
+    stats: &mut SearchStats,
+    checkpoint: &mut Checkpoint,
+    progress: &mut Progress,
+) -> u32 {
+    let count = blueprints.len().min(params.blueprints);
+    blueprints
+        .iter()
+        .take(count)
+        .enumerate()
+        .map(|(index, blueprint)| {
+            let progress = &mut progress.section(index, count);
+            evaluate_with(blueprint, params.max_time, stats, checkpoint, progress)
+        })
+        .product()
+}
+
+/// What is left of the blueprints in part 2, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The minutes of part 2.
+    pub max_time: u32,
+    /// The number of blueprints which are not eaten.
+    pub blueprints: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            max_time: 32,
+            blueprints: 3,
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = Vec<Blueprint>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| lines(text, blueprint))
+    }
+
+    fn part1(blueprints: &Self::Input<'_>) -> Self::Answer1 {
+        Self::part1_stats(blueprints, &mut SearchStats::default())
+    }
+
+    fn part2(blueprints: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_stats(blueprints, &mut SearchStats::default())
+    }
+}
+
+impl Configurable for Day19 {
+    type Params = Params;
+
+    /// The geodes are counted in 32 bits. A blueprint opens at most as many geodes as if a geode robot was bought every
+    /// minute, so the product has to fit even then.
+    fn check(params: &Params) -> Result<(), String> {
+        if params.blueprints == 0 {
+            return Err("at least one blueprint has to be left".to_owned());
+        }
+        let max_time = params.max_time;
+        let blueprints = u32::try_from(params.blueprints).unwrap_or(u32::MAX);
+        let product = max_time
+            .checked_mul(max_time.saturating_sub(1))
+            .and_then(|twice_the_geodes| (twice_the_geodes / 2).checked_pow(blueprints));
+        match product {
+            Some(_) => Ok(()),
+            None => Err(format!(
+                "the geodes of {blueprints} blueprints in {max_time} minutes could be too many to count"
+            )),
+        }
+    }
+
+    fn part1_with(blueprints: &Self::Input<'_>, _: &Params) -> Self::Answer1 {
+        Self::part1(blueprints)
+    }
+
+    fn part2_with(blueprints: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        max_geodes_product(
+            blueprints,
+            params,
+            stats,
+            checkpoint,
+            &mut Progress::ignored(),
+        )
+    }
+}
+
+impl Instrumented for Day19 {
+    fn part1_stats(blueprints: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer1 {
+        let checkpoint = &mut Checkpoint::disabled();
+        quality_level_sum(blueprints, stats, checkpoint, &mut Progress::ignored())
+    }
+
+    fn part2_stats(blueprints: &Self::Input<'_>, stats: &mut SearchStats) -> Self::Answer2 {
+        let checkpoint = &mut Checkpoint::disabled();
+        max_geodes_product(
+            blueprints,
+            &Params::default(),
+            stats,
+            checkpoint,
+            &mut Progress::ignored(),
+        )
+    }
+}
+
+impl Resumable for Day19 {
+    fn part1_resumable(
+        blueprints: &Self::Input<'_>,
+        _: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer1 {
+        let stats = &mut SearchStats::default();
+        quality_level_sum(blueprints, stats, checkpoint, &mut Progress::ignored())
+    }
+
+    fn part2_resumable(
+        blueprints: &Self::Input<'_>,
+        params: &Params,
+        checkpoint: &mut Checkpoint,
+    ) -> Self::Answer2 {
+        let stats = &mut SearchStats::default();
+        let progress = &mut Progress::ignored();
+        max_geodes_product(blueprints, params, stats, checkpoint, progress)
+    }
+}
+
+impl Cancellable for Day19 {
+    fn part1_cancellable(
+        blueprints: &Self::Input<'_>,
+        _: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer1> {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        let sum = quality_level_sum(blueprints, stats, checkpoint, progress);
+        Outcome::of(sum, progress)
+    }
+
+    fn part2_cancellable(
+        blueprints: &Self::Input<'_>,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer2> {
+        let stats = &mut SearchStats::default();
+        let checkpoint = &mut Checkpoint::disabled();
+        let product = max_geodes_product(blueprints, params, stats, checkpoint, progress);
+        Outcome::of(product, progress)
+    }
+}
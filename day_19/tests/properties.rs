@@ -0,0 +1,102 @@
+//! `evaluate` skips the branches which can not beat the best number of geodes found so far. These properties compare
+//! it with an exhaustive search on small generated blueprints, which only skips robots that could never be put to use.
+
+use common::Solution;
+use day_19::{evaluate, Blueprint, Day19};
+use proptest::prelude::*;
+
+#[derive(Clone, Copy, Default)]
+struct Stock {
+    robots: [u32; 4],
+    units: [u32; 4],
+}
+
+/// The costs of the ore, clay, obsidian and geode robots, in ore, clay and obsidian.
+fn costs(blueprint: &Blueprint) -> [[u32; 3]; 4] {
+    [
+        [blueprint.cost_per_ore_robot, 0, 0],
+        [blueprint.cost_per_clay_robot, 0, 0],
+        [
+            blueprint.cost_per_obisidan_robot[0],
+            blueprint.cost_per_obisidan_robot[1],
+            0,
+        ],
+        [
+            blueprint.cost_per_geode_robot[0],
+            0,
+            blueprint.cost_per_geode_robot[1],
+        ],
+    ]
+}
+
+fn reference(blueprint: &Blueprint, max_time: u32) -> u32 {
+    let costs = costs(blueprint);
+    // No more ore, clay or obsidian can be spent per minute than the most expensive robot costs
+    let limits = [0, 1, 2].map(|unit| costs.iter().map(|cost| cost[unit]).max().unwrap());
+    let mut stock = Stock::default();
+    stock.robots[0] = 1;
+    search(&costs, &limits, stock, max_time)
+}
+
+/// Either waits until the end, or waits until the next robot can be bought and buys it.
+fn search(costs: &[[u32; 3]; 4], limits: &[u32; 3], stock: Stock, time_left: u32) -> u32 {
+    let mut best = stock.units[3] + stock.robots[3] * time_left;
+    for (robot, cost) in costs.iter().enumerate() {
+        if robot < 3 && stock.robots[robot] >= limits[robot] {
+            continue;
+        }
+        let wait = (0..3).try_fold(0, |wait: u32, unit| {
+            match cost[unit].saturating_sub(stock.units[unit]) {
+                0 => Some(wait),
+                _ if stock.robots[unit] == 0 => None,
+                missing => Some(wait.max(missing.div_ceil(stock.robots[unit]))),
+            }
+        });
+        let Some(wait) = wait.filter(|wait| wait + 1 < time_left) else {
+            continue;
+        };
+
+        let mut next = stock;
+        for (units, robots) in next.units.iter_mut().zip(stock.robots) {
+            *units += robots * (wait + 1);
+        }
+        for (units, cost) in next.units.iter_mut().zip(cost) {
+            *units -= cost;
+        }
+        next.robots[robot] += 1;
+        best = best.max(search(costs, limits, next, time_left - wait - 1));
+    }
+    best
+}
+
+/// A blueprint with costs in the ranges of the puzzle, which are shrunk to the cheapest robots.
+fn blueprint() -> impl Strategy<Value = String> {
+    let ore = || 2..=4_u32;
+    (ore(), ore(), ore(), 5..=20_u32, ore(), 5..=20_u32).prop_map(
+        |(ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian)| {
+            format!(
+                "Blueprint 1: Each ore robot costs {ore} ore. Each clay robot costs {clay} ore. Each obsidian robot \
+                 costs {obsidian_ore} ore and {obsidian_clay} clay. Each geode robot costs {geode_ore} ore and \
+                 {geode_obsidian} obsidian."
+            )
+        },
+    )
+}
+
+#[test]
+fn reference_matches_the_example() {
+    let blueprints = Day19::parse(include_str!("../data/demo_input.txt")).unwrap();
+    assert_eq!(reference(&blueprints[0], 24), 9);
+    assert_eq!(reference(&blueprints[1], 24), 12);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 256, failure_persistence: None, ..ProptestConfig::default() })]
+
+    #[test]
+    fn evaluate_opens_the_most_geodes(input in blueprint(), max_time in 10..=24_u32) {
+        let blueprints = Day19::parse(&input).unwrap();
+        let blueprint = &blueprints[0];
+        prop_assert_eq!(evaluate(blueprint, max_time), reference(blueprint, max_time), "input:\n{}", input);
+    }
+}
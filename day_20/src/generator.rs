@@ -0,0 +1,23 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day20;
+
+/// `size` is the length of the list, at least two to have something to mix. Like in the puzzle, numbers repeat, but
+/// there is exactly one 0.
+impl Generator for Day20 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers = (1..size.max(2))
+            .map(|_| match rng.random_bool(0.5) {
+                true => rng.random_range(1..=10000),
+                false => -rng.random_range(1..=10000),
+            })
+            .collect::<Vec<i64>>();
+        numbers.insert(rng.random_range(0..=numbers.len()), 0);
+        numbers
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,171 @@
+use common::{
+    config::Configurable,
+    parse::{self, lines, signed},
+    progress::{Cancellable, Outcome, Progress},
+    ParseError, Solution,
+};
+use serde::{Deserialize, Serialize};
+
+mod generator;
+
+struct Number {
+    value: i64,
+    initial_position: i64,
+    position: i64,
+}
+
+/// Mixes the numbers, multiplied by `key`, the given number of rounds and sums up the grove coordinates.
+pub fn grove_coordinates_sum(input: &[i64], key: i64, rounds: usize) -> i64 {
+    grove_coordinates_sum_with(input, key, rounds, &mut Progress::ignored()).unwrap()
+}
+
+/// Like [`grove_coordinates_sum`], reporting the numbers mixed so far to the progress. It is `None` if the progress is
+/// cancelled before all of them are mixed.
+pub fn grove_coordinates_sum_with(
+    input: &[i64],
+    key: i64,
+    rounds: usize,
+    progress: &mut Progress,
+) -> Option<i64> {
+    // numbers are NOT unique!!!
+    let mut numbers = input
+        .iter()
+        .enumerate()
+        .map(|(position, value)| {
+            let position = position as i64;
+            Number {
+                value: value * key,
+                initial_position: position,
+                position,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let num_numbers = numbers.len() as i64;
+
+    let moves = rounds as f64 * num_numbers as f64;
+    for round in 0..rounds {
+        for position in 0..num_numbers {
+            progress.report((round as f64 * num_numbers as f64 + position as f64) / moves);
+            if progress.is_cancelled() {
+                return None;
+            }
+            let current_number = numbers
+                .iter_mut()
+                .find(|n| n.initial_position == position)
+                .unwrap();
+
+            let new_position = current_number.position + current_number.value;
+            let new_position = new_position.rem_euclid(num_numbers - 1);
+            let old_position = current_number.position;
+            let init_position = current_number.initial_position;
+            current_number.position = new_position;
+
+            let (lower, upper, offset) = if new_position > old_position {
+                (old_position, new_position, -1)
+            } else {
+                (new_position, old_position, 1)
+            };
+
+            numbers
+                .iter_mut()
+                .filter(|number| {
+                    number.position >= lower
+                        && number.position <= upper
+                        && init_position != number.initial_position
+                })
+                .for_each(|number| {
+                    number.position += offset;
+                });
+        }
+    }
+
+    let zero_position = numbers.iter().find(|number| number.value == 0).unwrap();
+
+    let sum = [1000, 2000, 3000]
+        .iter()
+        .map(|nth_number| (zero_position.position + (nth_number % num_numbers)) % num_numbers)
+        .map(|position| {
+            numbers
+                .iter()
+                .find(|number| number.position == position)
+                .unwrap()
+                .value
+        })
+        .sum::<i64>();
+    Some(sum)
+}
+
+/// The decryption of part 2, see [`Configurable`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The decryption key the numbers of part 2 are multiplied with.
+    pub key: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { key: 811589153 }
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let numbers: Vec<i64> =
+            parse::complete(input, |text| lines(text, |line| signed(line, "a number")))?;
+        if numbers.len() < 2 {
+            Err(ParseError::end(input, "at least two numbers to mix"))
+        } else if !numbers.contains(&0) {
+            Err(ParseError::end(input, "a 0 among the numbers"))
+        } else {
+            Ok(numbers)
+        }
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        grove_coordinates_sum(numbers, 1, 1)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        Self::part2_with(numbers, &Params::default())
+    }
+}
+
+impl Configurable for Day20 {
+    type Params = Params;
+
+    fn part1_with(numbers: &Self::Input<'_>, _: &Params) -> Self::Answer1 {
+        Self::part1(numbers)
+    }
+
+    fn part2_with(numbers: &Self::Input<'_>, params: &Params) -> Self::Answer2 {
+        grove_coordinates_sum(numbers, params.key, 10)
+    }
+}
+
+impl Cancellable for Day20 {
+    fn part1_cancellable(
+        numbers: &Self::Input<'_>,
+        _: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer1> {
+        grove_coordinates_sum_with(numbers, 1, 1, progress).into()
+    }
+
+    fn part2_cancellable(
+        numbers: &Self::Input<'_>,
+        params: &Params,
+        progress: &mut Progress,
+    ) -> Outcome<Self::Answer2> {
+        grove_coordinates_sum_with(numbers, params.key, 10, progress).into()
+    }
+}
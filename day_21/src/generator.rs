@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use common::generate::{Generator, Rng};
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Day21;
+
+/// Numbers grow when going down the tree through subtractions and divisions. Above this, only operations that make
+/// them smaller are used.
+const LARGE: i64 = 1_000_000_000_000;
+
+struct Riddle<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Riddle<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| self.rng.random_range('a'..='z'))
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Splits the monkeys of an operation into the two odd numbers of monkeys of its operands.
+    fn split(&mut self, monkeys: usize) -> (usize, usize) {
+        let left = self.rng.random_range(0..(monkeys - 1) / 2) * 2 + 1;
+        (left, monkeys - 1 - left)
+    }
+
+    /// A monkey yelling `value`, which depends on an odd number of monkeys, itself included. Divisions always work out
+    /// without remainder.
+    fn yelling(&mut self, value: i64, monkeys: usize) -> String {
+        let name = self.name();
+        if monkeys < 3 {
+            self.lines.push(format!("{name}: {value}"));
+            return name;
+        }
+        let (left_monkeys, right_monkeys) = self.split(monkeys);
+        let factor = self.rng.random_range(2..=5);
+        let (left, operation, right) = match self.rng.random_range(0..4) {
+            0 if value < LARGE => (value * factor, '/', factor),
+            1 if value < LARGE => (value + factor, '-', factor),
+            2 if divisor(value).is_some() => {
+                let divisor = divisor(value).unwrap();
+                (value / divisor, '*', divisor)
+            }
+            _ if value >= 2 => {
+                let left = self.rng.random_range(1..value);
+                (left, '+', value - left)
+            }
+            _ => (value + factor, '-', factor),
+        };
+        let left = self.yelling(left, left_monkeys);
+        let right = self.yelling(right, right_monkeys);
+        self.lines
+            .push(format!("{name}: {left} {operation} {right}"));
+        name
+    }
+
+    /// A monkey which has to yell `value` and depends on the human. The human yells exactly the number that makes it
+    /// yell `value`, so the number of the human is also the answer of part 2.
+    fn depending(&mut self, value: i64, monkeys: usize) -> String {
+        if monkeys < 3 {
+            self.lines.push(format!("humn: {value}"));
+            return "humn".to_owned();
+        }
+        let name = self.name();
+        let (human_monkeys, other_monkeys) = self.split(monkeys);
+        let human_first = self.rng.random_bool(0.5);
+        let factor = self.rng.random_range(2..=5);
+        // The operands of the operation, the human's one first
+        let subtraction = match human_first {
+            true => (value + factor, '-', factor),
+            false => (factor, '-', value + factor),
+        };
+        let (human, operation, other) = match self.rng.random_range(0..4) {
+            0 if value < LARGE => match human_first {
+                true => (value * factor, '/', factor),
+                false => (factor, '/', value * factor),
+            },
+            1 if value < LARGE => subtraction,
+            2 if divisor(value).is_some() => {
+                let divisor = divisor(value).unwrap();
+                (value / divisor, '*', divisor)
+            }
+            _ if value >= 2 => {
+                let other = self.rng.random_range(1..value);
+                (value - other, '+', other)
+            }
+            _ => subtraction,
+        };
+        let human = self.depending(human, human_monkeys);
+        let other = self.yelling(other, other_monkeys);
+        let (left, right) = match human_first {
+            true => (human, other),
+            false => (other, human),
+        };
+        self.lines
+            .push(format!("{name}: {left} {operation} {right}"));
+        name
+    }
+}
+
+/// A small divisor of `value`, other than 1 and itself.
+fn divisor(value: i64) -> Option<i64> {
+    (2..=100.min(value - 1)).find(|divisor| value % divisor == 0)
+}
+
+/// `size` is the number of monkeys, made odd and at least three. Both operands of `root` yell the same number, when the
+/// human yells the number given for `humn`.
+impl Generator for Day21 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let monkeys = size.max(3) | 1;
+        let value = rng.random_range(1..=1000);
+        let mut riddle = Riddle {
+            rng,
+            names: HashSet::new(),
+            lines: Vec::new(),
+        };
+        let (human_monkeys, other_monkeys) = riddle.split(monkeys);
+        let human = riddle.depending(value, human_monkeys);
+        let other = riddle.yelling(value, other_monkeys);
+        let (left, right) = match riddle.rng.random_bool(0.5) {
+            true => (human, other),
+            false => (other, human),
+        };
+        riddle.lines.push(format!("root: {left} + {right}"));
+        riddle.lines.shuffle(riddle.rng);
+        riddle.lines.join("\n")
+    }
+}
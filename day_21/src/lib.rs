@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, keyword, lines, signed, symbol, take_while, Failure, Parsed},
+    ParseError, Solution,
+};
+
+mod generator;
+
+#[derive(Clone, Copy)]
+pub enum Operation {
+    Addition,
+    Substraction,
+    Multiplication,
+    Division,
+}
+
+#[derive(Clone)]
+pub enum Monkey<'a> {
+    Number(i64),
+    Operation(&'a str, &'a str, Operation),
+    Human,
+}
+
+fn name(text: &str) -> Parsed<'_, &str> {
+    take_while(text, "the name of a monkey", |c| c.is_ascii_lowercase())
+}
+
+fn job(text: &str) -> Parsed<'_, Monkey<'_>> {
+    if let Ok((number, rest)) = signed(text, "a number") {
+        return Ok((Monkey::Number(number), rest));
+    }
+    let (name1, text) = name(text)
+        .map_err(|_| Failure::before(text, "a number or an operation like 'abcd + efgh'"))?;
+    let (_, text) = keyword(text, " ")?;
+    let (operation, text) = symbol(text, "'+', '-', '*' or '/'", |c| match c {
+        '+' => Some(Operation::Addition),
+        '-' => Some(Operation::Substraction),
+        '*' => Some(Operation::Multiplication),
+        '/' => Some(Operation::Division),
+        _ => None,
+    })?;
+    let (_, text) = keyword(text, " ")?;
+    let (name2, rest) = name(text)?;
+    Ok((Monkey::Operation(name1, name2, operation), rest))
+}
+
+fn monkey(text: &str) -> Parsed<'_, (&str, Monkey<'_>)> {
+    let (name, text) = name(text)?;
+    let (_, text) = keyword(text, ": ")?;
+    let (monkey, rest) = job(text)?;
+    Ok(((name, monkey), rest))
+}
+
+/// The number the monkey yells, if it does not depend on the human.
+pub fn evaluate(name: &str, map: &HashMap<&str, Monkey>) -> Option<i64> {
+    let monkey = &map[name];
+    match monkey {
+        Monkey::Number(number) => Some(*number),
+        Monkey::Operation(name1, name2, operation) => {
+            let number1 = evaluate(name1, map);
+            match operation {
+                Operation::Addition => {
+                    number1.and_then(|num1| evaluate(name2, map).map(|num2| num1 + num2))
+                }
+                Operation::Substraction => {
+                    number1.and_then(|num1| evaluate(name2, map).map(|num2| num1 - num2))
+                }
+                Operation::Multiplication => {
+                    number1.and_then(|num1| evaluate(name2, map).map(|num2| num1 * num2))
+                }
+                Operation::Division => {
+                    number1.and_then(|num1| evaluate(name2, map).map(|num2| num1 / num2))
+                }
+            }
+        }
+        Monkey::Human => None,
+    }
+}
+
+/// The number the human has to yell, so that the monkey yells `expected_result`.
+pub fn search_unknown(name: &str, map: &HashMap<&str, Monkey>, expected_result: i64) -> i64 {
+    let monkey = &map[name];
+    match monkey {
+        Monkey::Number(_) => unreachable!(),
+        Monkey::Operation(name1, name2, operation) => {
+            let result1 = evaluate(name1, map);
+            let known_result = result1.or(evaluate(name2, map)).unwrap();
+
+            let unknown_name = if result1.is_none() { name1 } else { name2 };
+            let expected_result = match operation {
+                Operation::Addition => expected_result - known_result,
+                Operation::Substraction => {
+                    if result1.is_some() {
+                        known_result - expected_result
+                    } else {
+                        expected_result + known_result
+                    }
+                }
+                Operation::Multiplication => expected_result / known_result,
+                Operation::Division => {
+                    if result1.is_some() {
+                        known_result / expected_result
+                    } else {
+                        known_result * expected_result
+                    }
+                }
+            };
+            search_unknown(unknown_name, map, expected_result)
+        }
+        Monkey::Human => expected_result,
+    }
+}
+
+/// How often the number of the monkey depends on the number of the human, or the name of a monkey which depends on its
+/// own number. `counted` holds the monkeys which were counted already, and `None` for the ones being counted.
+fn human_uses<'a>(
+    name: &'a str,
+    monkeys: &HashMap<&'a str, Monkey<'a>>,
+    counted: &mut HashMap<&'a str, Option<u64>>,
+) -> Result<u64, &'a str> {
+    match counted.get(name) {
+        Some(Some(uses)) => return Ok(*uses),
+        Some(None) => return Err(name),
+        None => {}
+    }
+    counted.insert(name, None);
+    let uses = match &monkeys[name] {
+        _ if name == "humn" => 1,
+        Monkey::Operation(name1, name2, _) => {
+            let uses1 = human_uses(name1, monkeys, counted)?;
+            uses1.saturating_add(human_uses(name2, monkeys, counted)?)
+        }
+        _ => 0,
+    };
+    counted.insert(name, Some(uses));
+    Ok(uses)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = HashMap<&'a str, Monkey<'a>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let monkeys = parse::complete(input, |text| lines(text, monkey))?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        // The names of the other monkeys are slices of the input, so they can be pointed at
+        let unknown = monkeys
+            .values()
+            .flat_map(|monkey| match monkey {
+                Monkey::Operation(name1, name2, _) => vec![*name1, *name2],
+                _ => vec![],
+            })
+            .find(|name| !monkeys.contains_key(name));
+        if let Some(name) = unknown {
+            return Err(ParseError::at(input, name, "the name of a monkey"));
+        }
+        match monkeys.get_key_value("root") {
+            Some((_, Monkey::Operation(..))) => {}
+            Some((name, _)) => {
+                return Err(ParseError::at(
+                    input,
+                    name,
+                    "a root monkey with an operation",
+                ))
+            }
+            None => return Err(ParseError::end(input, "a monkey named 'root'")),
+        }
+        // Part 2 finds the number of the human by undoing the operations between it and the root monkey one by one
+        let Some((human, _)) = monkeys.get_key_value("humn") else {
+            return Err(ParseError::end(input, "a monkey named 'humn'"));
+        };
+        match human_uses("root", &monkeys, &mut HashMap::new()) {
+            Ok(1) => Ok(monkeys),
+            Ok(_) => Err(ParseError::at(
+                input,
+                human,
+                "a human whose number the root monkey depends on once",
+            )),
+            Err(name) => Err(ParseError::at(
+                input,
+                name,
+                "a monkey which does not depend on its own number",
+            )),
+        }
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Self::Answer1 {
+        evaluate("root", monkeys).unwrap()
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> Self::Answer2 {
+        let mut monkeys = monkeys.clone();
+        monkeys.insert("humn", Monkey::Human);
+
+        let (name1, name2) = match monkeys["root"] {
+            Monkey::Operation(name1, name2, _) => (name1, name2),
+            _ => unreachable!(),
+        };
+        let result1 = evaluate(name1, &monkeys);
+
+        if let Some(result1) = result1 {
+            search_unknown(name2, &monkeys, result1)
+        } else {
+            let result2 = evaluate(name2, &monkeys);
+            search_unknown(name1, &monkeys, result2.unwrap())
+        }
+    }
+}
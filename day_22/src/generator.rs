@@ -0,0 +1,49 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::IndexedRandom, Rng as _};
+
+use crate::Day22;
+
+/// Some of the ways to unfold a cube, as the squares of its faces. The first one is the layout of the puzzle input, the
+/// second one the one of the example.
+const NETS: [&[&str]; 3] = [
+    &[" ##", " #", "##", "#"],
+    &["  #", "###", "  ##"],
+    &["#", "###", " #", " #"],
+];
+
+/// `size` is the side of the faces of the cube, at least 2. The path has twice as many moves as there are faces.
+impl Generator for Day22 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        let net = NETS.choose(rng).unwrap();
+        let mut lines = net
+            .iter()
+            .flat_map(|squares| {
+                (0..size)
+                    .map(|_| {
+                        squares
+                            .chars()
+                            .flat_map(|square| (0..size).map(move |_| square))
+                            .map(|square| match square {
+                                '#' if rng.random_bool(0.15) => '#',
+                                '#' => '.',
+                                _ => ' ',
+                            })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // The walk starts on the leftmost tile of the first row, which has to be open.
+        let start = lines[0].find(|tile| tile != ' ').unwrap();
+        lines[0].replace_range(start..start + 1, ".");
+
+        let steps = size.clamp(2, 255);
+        let mut path = rng.random_range(1..=steps).to_string();
+        for _ in 1..size * 12 {
+            path.push(if rng.random_bool(0.5) { 'L' } else { 'R' });
+            path += &rng.random_range(1..=steps).to_string();
+        }
+        format!("{}\n\n{path}", lines.join("\n"))
+    }
+}
@@ -0,0 +1,393 @@
+use std::{collections::HashSet, marker::PhantomData};
+
+use common::{
+    parse::{self, line_break, number, symbol, Failure, Parsed},
+    ParseError, Solution,
+};
+use geometry::{Direction, Point3, Turn};
+use grid::{Grid, Point};
+
+mod generator;
+mod simulation;
+
+pub enum Command {
+    Move(u8),
+    Turn(Turn),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+/// Where the walk continues when leaving the map.
+pub trait Wrapper {
+    fn wrap(map: &Map, position: &Point, direction: &Direction) -> (Point, Direction);
+}
+
+pub struct Map {
+    tiles: Grid<Option<Tile>>,
+    /// The map folded into a cube, for walking around it.
+    cube: Cube,
+}
+
+struct PositionIterator<'a, W>
+where
+    W: Wrapper,
+{
+    direction: Direction,
+    position: Point,
+    map: &'a Map,
+    phantom: std::marker::PhantomData<W>,
+}
+
+impl<'a, W> Iterator for PositionIterator<'a, W>
+where
+    W: Wrapper,
+{
+    type Item = (Point, Direction);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.position += self.direction;
+        if self.map.get(self.position).is_none() {
+            (self.position, self.direction) = W::wrap(self.map, &self.position, &self.direction);
+            assert!(self.map.get(self.position).is_some())
+        }
+
+        Some((self.position, self.direction))
+    }
+}
+
+impl Map {
+    fn row_min(&self, y: i64) -> Option<i64> {
+        self.tiles
+            .row(y as usize)
+            .position(|tile| tile.is_some())
+            .map(|x| x as i64)
+    }
+    fn row_max(&self, y: i64) -> Option<i64> {
+        self.tiles
+            .row(y as usize)
+            .rposition(|tile| tile.is_some())
+            .map(|x| x as i64)
+    }
+    fn col_min(&self, x: i64) -> Option<i64> {
+        self.tiles
+            .column(x as usize)
+            .position(|tile| tile.is_some())
+            .map(|y| y as i64)
+    }
+    fn col_max(&self, x: i64) -> Option<i64> {
+        self.tiles
+            .column(x as usize)
+            .rposition(|tile| tile.is_some())
+            .map(|y| y as i64)
+    }
+
+    pub fn get(&self, position: Point) -> Option<Tile> {
+        self.tiles.get(position).copied().flatten()
+    }
+}
+
+/// Continues on the opposite side of the map.
+pub struct FlatWrapper;
+
+impl Wrapper for FlatWrapper {
+    fn wrap(map: &'_ Map, position: &Point, direction: &Direction) -> (Point, Direction) {
+        let position = match direction {
+            Direction::Left => Point::new(map.row_max(position.y).unwrap(), position.y),
+            Direction::Right => Point::new(map.row_min(position.y).unwrap(), position.y),
+            Direction::Up => Point::new(position.x, map.col_max(position.x).unwrap()),
+            Direction::Down => Point::new(position.x, map.col_min(position.x).unwrap()),
+        };
+        (position, *direction)
+    }
+}
+
+/// Folds the map into a cube and continues on the adjacent face.
+pub struct CubeWrapper;
+
+type Vector = Point3<i32>;
+
+/// The orientation of a face of the cube: the directions of its x and y axis and of its outward normal in 3D.
+#[derive(Clone, Copy)]
+struct Face {
+    x: Vector,
+    y: Vector,
+    normal: Vector,
+}
+
+impl Face {
+    fn neighbor(&self, direction: Direction) -> Self {
+        match direction {
+            Direction::Right => Face {
+                x: -self.normal,
+                y: self.y,
+                normal: self.x,
+            },
+            Direction::Left => Face {
+                x: self.normal,
+                y: self.y,
+                normal: -self.x,
+            },
+            Direction::Down => Face {
+                x: self.x,
+                y: -self.normal,
+                normal: self.y,
+            },
+            Direction::Up => Face {
+                x: self.x,
+                y: self.normal,
+                normal: -self.y,
+            },
+        }
+    }
+
+    fn axis(&self, direction: Direction) -> Vector {
+        match direction {
+            Direction::Right => self.x,
+            Direction::Left => -self.x,
+            Direction::Down => self.y,
+            Direction::Up => -self.y,
+        }
+    }
+}
+
+/// The faces of the cube a map folds into, by their position within the map measured in squares.
+struct Cube {
+    square_size: i64,
+    faces: Vec<(Point, Face)>,
+}
+
+impl Cube {
+    /// Folds the map into a cube, starting with the face of the start position lying flat. `None` if the map is not
+    /// the net of a cube.
+    fn fold(tiles: &Grid<Option<Tile>>) -> Option<Self> {
+        let count = tiles.values().filter(|tile| tile.is_some()).count();
+        let square_size = ((count / 6) as f64).sqrt() as i64;
+        if square_size == 0 || count != 6 * (square_size * square_size) as usize {
+            return None;
+        }
+        // With six whole squares of tiles, there are no tiles left outside of them
+        let is_face = |square: Point| {
+            (0..square_size * square_size).all(|index| {
+                let position = Point::new(
+                    square.x * square_size + index % square_size,
+                    square.y * square_size + index / square_size,
+                );
+                tiles.get(position).copied().flatten().is_some()
+            })
+        };
+        let start = tiles.row(0).position(|tile| tile.is_some())? as i64;
+        let first = Point::new(start / square_size, 0);
+        if !is_face(first) {
+            return None;
+        }
+        let mut faces = vec![(
+            first,
+            Face {
+                x: Vector::new(1, 0, 0),
+                y: Vector::new(0, 1, 0),
+                normal: Vector::new(0, 0, 1),
+            },
+        )];
+
+        let mut index = 0;
+        while let Some(&(square, face)) = faces.get(index) {
+            for direction in Direction::ALL {
+                let neighbor = square + direction;
+                if is_face(neighbor) && faces.iter().all(|(known, _)| known != &neighbor) {
+                    faces.push((neighbor, face.neighbor(direction)));
+                }
+            }
+            index += 1;
+        }
+        let normals = faces
+            .iter()
+            .map(|(_, face)| face.normal)
+            .collect::<HashSet<_>>();
+        (faces.len() == 6 && normals.len() == 6).then_some(Self { square_size, faces })
+    }
+}
+
+impl Wrapper for CubeWrapper {
+    fn wrap(map: &Map, position: &Point, direction: &Direction) -> (Point, Direction) {
+        let (square_size, faces) = (map.cube.square_size, &map.cube.faces);
+
+        // The last position on the map, before walking over the edge
+        let last = *position - *direction;
+        let square = Point::new(last.x / square_size, last.y / square_size);
+        let (_, face) = faces.iter().find(|(known, _)| known == &square).unwrap();
+        let (offset_x, offset_y) = (last.x % square_size, last.y % square_size);
+
+        // Walking over the edge leads onto the face pointing into the walking direction, where one continues in the
+        // opposite direction of the current face's normal.
+        let (target_square, target) = faces
+            .iter()
+            .find(|(_, target)| target.normal == face.axis(*direction))
+            .unwrap();
+        let new_direction = Direction::ALL
+            .into_iter()
+            .find(|new_direction| target.axis(*new_direction) == -face.normal)
+            .unwrap();
+
+        // The offset along the common edge stays the same, but its axis might be flipped on the target face.
+        let (edge, offset) = match direction {
+            Direction::Left | Direction::Right => (face.y, offset_y),
+            Direction::Up | Direction::Down => (face.x, offset_x),
+        };
+        let target_edge = match new_direction {
+            Direction::Left | Direction::Right => target.y,
+            Direction::Up | Direction::Down => target.x,
+        };
+        let offset = if edge == target_edge {
+            offset
+        } else {
+            square_size - 1 - offset
+        };
+
+        let last = square_size - 1;
+        let (new_x, new_y) = match new_direction {
+            Direction::Right => (0, offset),
+            Direction::Left => (last, offset),
+            Direction::Down => (offset, 0),
+            Direction::Up => (offset, last),
+        };
+        let new_position = Point::new(
+            target_square.x * square_size + new_x,
+            target_square.y * square_size + new_y,
+        );
+        (new_position, new_direction)
+    }
+}
+
+fn command(text: &str) -> Parsed<'_, Command> {
+    let turn = symbol(text, "L or R", |c| match c {
+        'L' => Some(Turn::Left),
+        'R' => Some(Turn::Right),
+        _ => None,
+    });
+    match turn {
+        Ok((turn, rest)) => Ok((Command::Turn(turn), rest)),
+        Err(_) => number(text, "a number of steps, L or R")
+            .map(|(steps, rest)| (Command::Move(steps), rest)),
+    }
+}
+
+/// The commands of the path, which are written without anything between them.
+fn commands(text: &str) -> Parsed<'_, Vec<Command>> {
+    let (first, mut text) = command(text)?;
+    let mut commands = vec![first];
+    while !text.is_empty() && line_break(text).is_err() {
+        let (next, rest) = command(text)?;
+        commands.push(next);
+        text = rest;
+    }
+    Ok((commands, text))
+}
+
+fn map(text: &str) -> Parsed<'_, Map> {
+    let (tiles, rest) = Grid::parse(text, "'.', '#' or ' '", |c| match c {
+        '.' => Some(Some(Tile::Open)),
+        '#' => Some(Some(Tile::Wall)),
+        ' ' => Some(None),
+        _ => None,
+    })?;
+    match Cube::fold(&tiles) {
+        Some(cube) => Ok((Map { tiles, cube }, rest)),
+        None => Err(Failure::before(text, "a map which folds into a cube")),
+    }
+}
+
+fn parse(input: &str) -> Result<(Map, Vec<Command>), ParseError> {
+    parse::complete(input, |text| {
+        let (map, text) = map(text)?;
+        let (_, text) = line_break(text)
+            .map_err(|_| Failure::before(text, "an empty line followed by the path"))?;
+        let (commands, rest) = commands(text)?;
+        Ok(((map, commands), rest))
+    })
+}
+
+fn start_position(map: &Map) -> Point {
+    Point::new(map.row_min(0).unwrap(), 0)
+}
+
+/// The final position and direction after following the commands from the start position.
+pub fn execute_commands<W>(commands: &[Command], map: &Map) -> (Point, Direction)
+where
+    W: Wrapper,
+{
+    commands.iter().fold(
+        (start_position(map), Direction::Right),
+        |(position, direction), command| {
+            follow::<W>(map, command, position, direction)
+                .last()
+                .copied()
+                .unwrap_or((position, direction))
+        },
+    )
+}
+
+pub fn get_password(position: Point, direction: Direction) -> i64 {
+    let direction_value = match direction {
+        Direction::Left => 2,
+        Direction::Right => 0,
+        Direction::Up => 3,
+        Direction::Down => 1,
+    };
+    1000 * (position.y + 1) + 4 * (position.x + 1) + direction_value
+}
+
+/// The positions and directions one after the other while following the command. A turn is a single step on the
+/// spot, a move stops in front of a wall.
+fn follow<W>(
+    map: &Map,
+    command: &Command,
+    position: Point,
+    direction: Direction,
+) -> Vec<(Point, Direction)>
+where
+    W: Wrapper,
+{
+    match command {
+        Command::Move(steps) => {
+            let iterator = PositionIterator::<'_, W> {
+                map,
+                position,
+                direction,
+                phantom: PhantomData,
+            };
+            iterator
+                .take((*steps).into())
+                .take_while(|(position, _)| map.get(*position).unwrap() == Tile::Open)
+                .collect()
+        }
+        Command::Turn(orientation) => vec![(position, direction.turn(*orientation))],
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input<'a> = (Map, Vec<Command>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, commands): &Self::Input<'_>) -> Self::Answer1 {
+        let (position, direction) = execute_commands::<FlatWrapper>(commands, map);
+        get_password(position, direction)
+    }
+
+    fn part2((map, commands): &Self::Input<'_>) -> Self::Answer2 {
+        let (position, direction) = execute_commands::<CubeWrapper>(commands, map);
+        get_password(position, direction)
+    }
+}
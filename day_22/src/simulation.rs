@@ -0,0 +1,71 @@
+use std::{collections::HashMap, iter, rc::Rc};
+
+use common::{
+    visualize::{Frames, Simulation},
+    Part,
+};
+use geometry::Direction;
+use grid::Point;
+
+use crate::{follow, start_position, Command, CubeWrapper, Day22, FlatWrapper, Map, Tile, Wrapper};
+
+/// Renders the map like in the puzzle, with the way taken so far drawn as arrows in the last facing on each tile.
+fn render(map: &Map, trail: &HashMap<Point, Direction>) -> String {
+    (0..map.tiles.height())
+        .flat_map(|y| {
+            (0..map.tiles.width())
+                .map(move |x| {
+                    let position = Point::new(x as i64, y as i64);
+                    match (trail.get(&position), map.get(position)) {
+                        (Some(Direction::Up), _) => '^',
+                        (Some(Direction::Right), _) => '>',
+                        (Some(Direction::Down), _) => 'v',
+                        (Some(Direction::Left), _) => '<',
+                        (None, Some(Tile::Open)) => '.',
+                        (None, Some(Tile::Wall)) => '#',
+                        (None, None) => ' ',
+                    }
+                })
+                .chain(iter::once('\n'))
+        })
+        .collect()
+}
+
+fn walk<W>((map, commands): (Map, Vec<Command>)) -> Frames<'static>
+where
+    W: Wrapper,
+{
+    let start = (start_position(&map), Direction::Right);
+    let mut trail = HashMap::from([start]);
+    let first = render(&map, &trail);
+    // Both following the commands and rendering need the map
+    let map = Rc::new(map);
+    let steps = commands
+        .into_iter()
+        .scan(start, {
+            let map = map.clone();
+            move |(position, direction), command| {
+                let steps = follow::<W>(&map, &command, *position, *direction);
+                if let Some(last) = steps.last() {
+                    (*position, *direction) = *last;
+                }
+                Some(steps)
+            }
+        })
+        .flatten()
+        .map(move |(position, direction)| {
+            trail.insert(position, direction);
+            render(&map, &trail)
+        });
+    Box::new(iter::once(first).chain(steps))
+}
+
+/// One frame per step or turn, wrapping around the flat map in part 1 and around the cube in part 2.
+impl Simulation for Day22 {
+    fn frames(input: Self::Input<'_>, part: Part) -> Frames<'_> {
+        match part {
+            Part::One => walk::<FlatWrapper>(input),
+            Part::Two => walk::<CubeWrapper>(input),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::Day23;
+
+/// `size` is the side of the square scan, of which about half is covered by elves.
+impl Generator for Day23 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.random_bool(0.5) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
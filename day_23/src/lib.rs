@@ -0,0 +1,240 @@
+use std::{collections::HashMap, fmt::Display, iter};
+
+use automaton::{Automaton, Rules, Tick};
+use common::{parse, ParseError, Part, Solution};
+use geometry::Direction8;
+use grid::{Point, SparseGrid};
+
+mod generator;
+mod render;
+mod simulation;
+
+/// The order in which the elves consider the directions: north, south, west and east.
+pub fn next_direction(direction: &Direction8) -> Option<Direction8> {
+    match direction {
+        Direction8::North => Some(Direction8::South),
+        Direction8::South => Some(Direction8::West),
+        Direction8::West => Some(Direction8::East),
+        Direction8::East => Some(Direction8::North),
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AreaPiece {
+    Elf,
+    Empty,
+}
+
+#[derive(Clone, Copy)]
+pub struct Elf;
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#")
+    }
+}
+
+#[derive(Clone)]
+pub struct Area {
+    elfs: SparseGrid<Elf>,
+}
+
+impl Area {
+    pub fn get(&self, coordinates: Point) -> AreaPiece {
+        if self.elfs.contains(coordinates) {
+            AreaPiece::Elf
+        } else {
+            AreaPiece::Empty
+        }
+    }
+
+    fn get_neighbors(&self, coordinates: &Point) -> HashMap<Direction8, AreaPiece> {
+        Direction8::ALL
+            .into_iter()
+            .map(|direction| (direction, self.get(*coordinates + direction)))
+            .collect()
+    }
+
+    pub fn height(&self) -> i64 {
+        self.elfs.bounds().map_or(0, |(min, max)| max.y - min.y + 1)
+    }
+    pub fn width(&self) -> i64 {
+        self.elfs.bounds().map_or(0, |(min, max)| max.x - min.x + 1)
+    }
+    pub fn area(&self) -> i64 {
+        self.height() * self.width()
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.elfs)
+    }
+}
+
+fn parse(input: &str) -> Result<Area, ParseError> {
+    let elfs = parse::complete(input, |text| {
+        SparseGrid::parse(text, '.', "'.' or '#'", |c| (c == '#').then_some(Elf))
+    })?;
+    Ok(Area { elfs })
+}
+
+/// The move an elf wants to make. Elves that stay have the same origin and target.
+pub struct Proposition {
+    pub origin: Point,
+    pub target: Point,
+}
+
+fn can_walk(direction: Direction8, neighbors: &HashMap<Direction8, AreaPiece>) -> bool {
+    let checks = match direction {
+        Direction8::North => [
+            Direction8::North,
+            Direction8::NorthEast,
+            Direction8::NorthWest,
+        ],
+        Direction8::South => [
+            Direction8::South,
+            Direction8::SouthEast,
+            Direction8::SouthWest,
+        ],
+        Direction8::West => [
+            Direction8::NorthWest,
+            Direction8::SouthWest,
+            Direction8::West,
+        ],
+        Direction8::East => [
+            Direction8::East,
+            Direction8::NorthEast,
+            Direction8::SouthEast,
+        ],
+        _ => unreachable!(),
+    };
+    checks
+        .iter()
+        .all(|direction| neighbors[direction] == AreaPiece::Empty)
+}
+
+pub fn get_propositions(area: &Area, direction: Direction8) -> Vec<Proposition> {
+    area.elfs
+        .points()
+        .map(|elf| {
+            let neighbors = area.get_neighbors(&elf);
+            if neighbors
+                .values()
+                .all(|neighbor| neighbor == &AreaPiece::Empty)
+            {
+                return Proposition {
+                    origin: elf,
+                    target: elf,
+                };
+            }
+            let walk_direction = iter::successors(Some(direction), next_direction)
+                .take(4)
+                .find(|actual_direction| can_walk(*actual_direction, &neighbors));
+            Proposition {
+                origin: elf,
+                target: match walk_direction {
+                    Some(direction) => elf + direction,
+                    None => elf,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Moves the elves, unless several of them want to move to the same position.
+pub fn merge_propositions(propositions: &[Proposition]) -> Area {
+    let mut x = HashMap::new();
+    for proposition in propositions {
+        if let Some(old) = x.insert(proposition.target, proposition) {
+            x.remove(&proposition.target);
+            x.insert(proposition.origin, proposition);
+            x.insert(old.origin, old);
+        }
+    }
+    let elfs = x.keys().map(|target| (*target, Elf)).collect();
+    Area { elfs }
+}
+
+/// The elves, and the direction they consider first in the next round.
+struct Grove {
+    area: Area,
+    direction: Direction8,
+}
+
+impl Grove {
+    fn new(area: Area) -> Self {
+        Self {
+            area,
+            direction: Direction8::North,
+        }
+    }
+}
+
+/// One round per tick, until no elf moves anymore.
+struct Spreading;
+
+impl Rules for Spreading {
+    type State = Grove;
+
+    fn tick(&self, grove: &mut Grove) -> Tick {
+        let propositions = get_propositions(&grove.area, grove.direction);
+        if propositions
+            .iter()
+            .all(|proposition| proposition.origin == proposition.target)
+        {
+            return Tick::Unchanged;
+        }
+        grove.area = merge_propositions(&propositions);
+        grove.direction = next_direction(&grove.direction).unwrap();
+        Tick::Changed
+    }
+}
+
+/// The number of rounds of part 1. Part 2 goes on until no elf moves anymore.
+fn rounds(part: Part) -> usize {
+    match part {
+        Part::One => 10,
+        Part::Two => usize::MAX,
+    }
+}
+
+/// The area before the first round and after every round in which an elf moved, as seen through `view`. There are at
+/// most `rounds` rounds.
+fn spread<T>(
+    area: Area,
+    rounds: usize,
+    mut view: impl FnMut(&Area) -> T,
+) -> impl Iterator<Item = T> {
+    Automaton::new(Spreading, Grove::new(area))
+        .states(move |grove| view(&grove.area))
+        .take(rounds.saturating_add(1))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input<'a> = Area;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(area: &Self::Input<'_>) -> Self::Answer1 {
+        let mut spreading = Automaton::new(Spreading, Grove::new(area.clone()));
+        spreading.run(10);
+        let area = &spreading.state().area;
+        area.area() - area.elfs.len() as i64
+    }
+
+    fn part2(area: &Self::Input<'_>) -> Self::Answer2 {
+        let mut spreading = Automaton::new(Spreading, Grove::new(area.clone()));
+        spreading.fixpoint();
+        spreading.ticks()
+    }
+}
@@ -0,0 +1,31 @@
+use common::{
+    render::{Picture, Pictures, Render, Rgb},
+    Part,
+};
+
+use grid::Point;
+
+use crate::{rounds, spread, Area, AreaPiece, Day23};
+
+impl Area {
+    fn picture(&self) -> Picture {
+        let (min, max) = self.elfs.bounds().unwrap_or_default();
+        Picture::from_fn((min.x, min.y), (max.x, max.y), |x, y| {
+            match self.get(Point::new(x, y)) {
+                AreaPiece::Empty => 0,
+                AreaPiece::Elf => 1,
+            }
+        })
+    }
+}
+
+/// One picture per round, like the frames of the simulation.
+impl Render for Day23 {
+    fn palette() -> Vec<Rgb> {
+        vec![[0xf0, 0xf4, 0xf8], [0x20, 0x80, 0x40]]
+    }
+
+    fn pictures<'a>(area: &'a Self::Input<'_>, part: Part) -> Pictures<'a> {
+        Box::new(spread(area.clone(), rounds(part), Area::picture))
+    }
+}
@@ -0,0 +1,13 @@
+use common::{
+    visualize::{Frames, Simulation},
+    Part,
+};
+
+use crate::{rounds, spread, Day23};
+
+/// One frame per round.
+impl Simulation for Day23 {
+    fn frames(area: Self::Input<'_>, part: Part) -> Frames<'_> {
+        Box::new(spread(area, rounds(part), ToString::to_string))
+    }
+}
@@ -0,0 +1,32 @@
+use common::generate::{Generator, Rng};
+use rand::{seq::IndexedRandom, Rng as _};
+
+use crate::Day24;
+
+/// `size` is the height of the inside of the valley, which is five times as wide. Its width being a multiple of its
+/// height keeps the period of the blizzards short. About a quarter of the valley is covered by blizzards, none of them
+/// moves up or down in the columns of the entrance and the exit, like in the puzzle.
+impl Generator for Day24 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let height = size.max(2);
+        let width = height * 5;
+        let wall = |opening: usize| {
+            (0..width + 2)
+                .map(|x| if x == opening { '.' } else { '#' })
+                .collect::<String>()
+        };
+        let mut lines = vec![wall(1)];
+        for _ in 0..height {
+            let row = (1..=width)
+                .map(|x| match rng.random_bool(0.25) {
+                    true if x == 1 || x == width => *['<', '>'].choose(rng).unwrap(),
+                    true => *['<', '>', '^', 'v'].choose(rng).unwrap(),
+                    false => '.',
+                })
+                .collect::<String>();
+            lines.push(format!("#{row}#"));
+        }
+        lines.push(wall(width));
+        lines.join("\n")
+    }
+}
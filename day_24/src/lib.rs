@@ -0,0 +1,188 @@
+use std::{collections::HashSet, iter};
+
+use automaton::{Automaton, Rules, Tick};
+use common::{parse, MaybeAnswer, ParseError, Solution};
+use geometry::Direction;
+use grid::{Grid, Point};
+
+mod generator;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Wall,
+    Open,
+    Blizzard(Direction),
+}
+
+pub struct Valley {
+    walls: Grid<bool>,
+    pub start: Point,
+    pub end: Point,
+    /// The positions covered by blizzards, for every minute of a period after which they repeat.
+    blizzards: Vec<Grid<bool>>,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+/// The blizzards move one step per tick, and wrap around the inner part of the valley of the given size.
+struct Blowing {
+    width: i64,
+    height: i64,
+}
+
+impl Rules for Blowing {
+    type State = Vec<(Point, Direction)>;
+
+    fn tick(&self, blizzards: &mut Self::State) -> Tick {
+        for (position, direction) in blizzards.iter_mut() {
+            let next = *position + *direction;
+            *position = Point::new(
+                (next.x - 1).rem_euclid(self.width) + 1,
+                (next.y - 1).rem_euclid(self.height) + 1,
+            );
+        }
+        match blizzards.is_empty() {
+            true => Tick::Unchanged,
+            false => Tick::Changed,
+        }
+    }
+}
+
+/// Moves all blizzards through the inner part of the valley, until they are back at their starting positions. Each of
+/// them moves along a row or a column, so they all are after a number of minutes which both the width and the height
+/// of the inner part divide.
+fn simulate_blizzards(tiles: &Grid<Tile>) -> Vec<Grid<bool>> {
+    let (width, height) = (tiles.width() - 2, tiles.height() - 2);
+    let blizzards = tiles
+        .iter()
+        .filter_map(|(position, tile)| match tile {
+            Tile::Blizzard(direction) => Some((position, *direction)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let rules = Blowing {
+        width: width as i64,
+        height: height as i64,
+    };
+
+    // Without any blizzards, the valley stays the same from the first minute on
+    Automaton::new(rules, blizzards)
+        .states(|blizzards| {
+            let mut covered = Grid::new(tiles.width(), tiles.height(), false);
+            for (position, _) in blizzards {
+                covered[*position] = true;
+            }
+            covered
+        })
+        .take(lcm(width, height))
+        .collect()
+}
+
+impl Valley {
+    fn is_free(&self, position: Point, minute: usize) -> bool {
+        self.walls.get(position) == Some(&false)
+            && !self.blizzards[minute % self.blizzards.len()][position]
+    }
+}
+
+/// The minute of reaching `to` when leaving `from` at `start_minute`. Searches the positions of each minute, waiting
+/// is allowed. Once a position is reached at the same point of the blizzard period again, it is not searched again.
+pub fn crossing(valley: &Valley, from: Point, to: Point, start_minute: usize) -> Option<usize> {
+    let period = valley.blizzards.len();
+    let mut visited = HashSet::new();
+    let mut positions = vec![from];
+    let mut minute = start_minute;
+
+    while !positions.is_empty() {
+        minute += 1;
+        let mut next_positions = Vec::new();
+        for position in positions {
+            for candidate in iter::once(position).chain(position.neighbors4()) {
+                if candidate == to {
+                    return Some(minute);
+                }
+                if valley.is_free(candidate, minute) && visited.insert((candidate, minute % period))
+                {
+                    next_positions.push(candidate);
+                }
+            }
+        }
+        positions = next_positions;
+    }
+    None
+}
+
+/// The single opening in the top or bottom wall.
+fn opening(input: &str, tiles: &Grid<Tile>, y: usize) -> Result<Point, ParseError> {
+    let mut openings = tiles
+        .row(y)
+        .enumerate()
+        .filter(|(_, tile)| **tile != Tile::Wall);
+    match (openings.next(), openings.next()) {
+        (Some((x, Tile::Open)), None) => Ok(Point::new(x as i64, y as i64)),
+        _ => {
+            let line = input.lines().nth(y).unwrap_or(input);
+            Err(ParseError::at(input, line, "a wall with a single opening"))
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Valley, ParseError> {
+    let tiles = parse::complete(input, |text| {
+        Grid::parse(text, "'#', '.', '>', '<', '^' or 'v'", |c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Open),
+            '>' => Some(Tile::Blizzard(Direction::Right)),
+            '<' => Some(Tile::Blizzard(Direction::Left)),
+            '^' => Some(Tile::Blizzard(Direction::Up)),
+            'v' => Some(Tile::Blizzard(Direction::Down)),
+            _ => None,
+        })
+    })?;
+    if tiles.width() < 3 || tiles.height() < 3 {
+        return Err(ParseError::end(input, "a valley surrounded by walls"));
+    }
+
+    let start = opening(input, &tiles, 0)?;
+    let end = opening(input, &tiles, tiles.height() - 1)?;
+    Ok(Valley {
+        walls: tiles.map(|tile| *tile == Tile::Wall),
+        start,
+        end,
+        blizzards: simulate_blizzards(&tiles),
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = Valley;
+    type Answer1 = MaybeAnswer<usize>;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(valley: &Self::Input<'_>) -> Self::Answer1 {
+        crossing(valley, valley.start, valley.end, 0).into()
+    }
+
+    fn part2(valley: &Self::Input<'_>) -> Self::Answer2 {
+        crossing(valley, valley.start, valley.end, 0)
+            .and_then(|there| crossing(valley, valley.end, valley.start, there))
+            .and_then(|back| crossing(valley, valley.start, valley.end, back))
+            .into()
+    }
+}
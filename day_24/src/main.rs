@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use common::Day;
+
+fn main() -> ExitCode {
+    common::run(&[Day::new::<day_24::Day24>()])
+}
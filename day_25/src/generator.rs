@@ -0,0 +1,17 @@
+use common::generate::{Generator, Rng};
+use rand::Rng as _;
+
+use crate::{Day25, Snafu};
+
+/// `size` is the number of fuel requirements, from a single digit up to twenty.
+impl Generator for Day25 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let limit = 5_i64.pow(rng.random_range(1..=20));
+                Snafu::from(rng.random_range(1..limit)).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,32 @@
+use common::{
+    parse::{self, lines},
+    ParseError, Solution,
+};
+
+mod generator;
+mod snafu;
+
+pub use snafu::Snafu;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = Vec<Snafu>;
+    type Answer1 = Snafu;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::complete(input, |text| lines(text, snafu::snafu))
+    }
+
+    fn part1(fuel_requirements: &Self::Input<'_>) -> Self::Answer1 {
+        fuel_requirements.iter().sum()
+    }
+
+    /// The last day has no second puzzle, its star is given for having all the others.
+    fn part2(_: &Self::Input<'_>) -> Self::Answer2 {
+        "Merry Christmas!"
+    }
+}
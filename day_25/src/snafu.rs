@@ -0,0 +1,271 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign},
+    str::FromStr,
+};
+
+use common::{
+    parse::{self, take_while, Parsed},
+    ParseError,
+};
+
+/// A number in balanced base 5: every digit is worth -2 to 2 times its power of five, written as `=`, `-`, `0`, `1`
+/// and `2`.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Snafu {
+    /// The digits, least significant first and without leading zeros. Zero has no digits at all.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn from_digits(mut digits: Vec<i8>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+
+    /// Adds two digits and the incoming carry, returning the outgoing carry and the resulting digit.
+    fn add_digits(a: i8, b: i8, carry: i8) -> (i8, i8) {
+        match a + b + carry {
+            sum @ 3.. => (1, sum - 5),
+            sum @ ..=-3 => (-1, sum + 5),
+            sum => (0, sum),
+        }
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        // `value * 5 + digit`, which leaves the range on the way to its ends, like `i128::MIN`, if multiplied first
+        self.digits.iter().rev().try_fold(0i128, |value, digit| {
+            value
+                .checked_mul(4)?
+                .checked_add(*digit as i128)?
+                .checked_add(value)
+        })
+    }
+}
+
+/// Parses the digits of a SNAFU number, most significant first.
+pub fn snafu(text: &str) -> Parsed<'_, Snafu> {
+    let (digits, rest) = take_while(text, "a SNAFU digit", |c| "=-012".contains(c))?;
+    let digits = digits
+        .bytes()
+        .rev()
+        .map(|digit| match digit {
+            b'=' => -2,
+            b'-' => -1,
+            digit => (digit - b'0') as i8,
+        })
+        .collect();
+    Ok((Snafu::from_digits(digits), rest))
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, snafu)
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for digit in self.digits.iter().rev() {
+            let c = match digit {
+                -2 => '=',
+                -1 => '-',
+                0 => '0',
+                1 => '1',
+                2 => '2',
+                _ => unreachable!("Digits are always balanced"),
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(mut value: i128) -> Self {
+        let mut digits = Vec::new();
+        while value != 0 {
+            // Taking a negative digit away first would overflow at the ends of the range, so it is carried afterwards
+            let (carry, digit) = match value.rem_euclid(5) {
+                digit @ 0..=2 => (0, digit),
+                digit => (1, digit - 5),
+            };
+            digits.push(digit as i8);
+            value = value.div_euclid(5) + carry;
+        }
+        Self::from_digits(digits)
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl TryFrom<&Snafu> for i128 {
+    type Error = &'static str;
+
+    fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+        snafu.to_i128().ok_or("SNAFU number out of range")
+    }
+}
+
+impl TryFrom<&Snafu> for i64 {
+    type Error = &'static str;
+
+    fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+        snafu
+            .to_i128()
+            .and_then(|value| value.try_into().ok())
+            .ok_or("SNAFU number out of range")
+    }
+}
+
+/// Adds digit by digit, carrying over whenever a sum leaves the range of a balanced digit.
+impl Add<&Snafu> for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: &Snafu) -> Self::Output {
+        let length = self.digits.len().max(rhs.digits.len()) + 1;
+        let digit = |snafu: &Snafu, index: usize| snafu.digits.get(index).copied().unwrap_or(0);
+        let mut carry = 0;
+        let digits = (0..length)
+            .map(|index| {
+                let (next_carry, sum) =
+                    Snafu::add_digits(digit(self, index), digit(rhs, index), carry);
+                carry = next_carry;
+                sum
+            })
+            .collect();
+        Snafu::from_digits(digits)
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl AddAssign<&Snafu> for Snafu {
+    fn add_assign(&mut self, rhs: &Snafu) {
+        *self = &*self + rhs;
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, snafu| &sum + snafu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The table of the puzzle description, decimal next to SNAFU.
+    const TABLE: [(i64, &str); 28] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+        (1747, "1=-0-2"),
+        (906, "12111"),
+        (198, "2=0="),
+        (11, "21"),
+        (201, "2=01"),
+        (31, "111"),
+        (1257, "20012"),
+        (32, "112"),
+        (353, "1=-1="),
+        (107, "1-12"),
+        (7, "12"),
+        (3, "1="),
+        (37, "122"),
+    ];
+
+    #[test]
+    fn parses_the_table() {
+        for (decimal, snafu) in TABLE {
+            let snafu = snafu.parse::<Snafu>().unwrap();
+            assert_eq!(i64::try_from(&snafu), Ok(decimal));
+            assert_eq!(i128::try_from(&snafu), Ok(decimal as i128));
+        }
+    }
+
+    #[test]
+    fn displays_the_table() {
+        for (decimal, snafu) in TABLE {
+            assert_eq!(Snafu::from(decimal).to_string(), snafu);
+            assert_eq!(Snafu::from(decimal as i128).to_string(), snafu);
+        }
+    }
+
+    #[test]
+    fn adds_without_converting() {
+        for (a, snafu_a) in TABLE {
+            for (b, snafu_b) in TABLE {
+                let sum = snafu_a.parse::<Snafu>().unwrap() + snafu_b.parse().unwrap();
+                assert_eq!(sum, Snafu::from(a + b));
+            }
+        }
+    }
+
+    #[test]
+    fn sums_the_demo() {
+        let sum = TABLE[15..]
+            .iter()
+            .map(|(_, snafu)| snafu.parse::<Snafu>().unwrap())
+            .collect::<Vec<_>>()
+            .iter()
+            .sum::<Snafu>();
+        assert_eq!(sum.to_string(), "2=-1=0");
+        assert_eq!(i64::try_from(&sum), Ok(4890));
+    }
+
+    #[test]
+    fn handles_zero_and_negative_numbers() {
+        assert_eq!(Snafu::from(0i64).to_string(), "0");
+        assert_eq!("000".parse::<Snafu>().unwrap(), Snafu::default());
+        assert_eq!(Snafu::from(-3i64).to_string(), "-2");
+        assert_eq!(Snafu::from(7i64) + Snafu::from(-7i64), Snafu::default());
+    }
+
+    #[test]
+    fn reports_invalid_digits() {
+        let error = "12a=".parse::<Snafu>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!("".parse::<Snafu>().is_err());
+    }
+
+    #[test]
+    fn detects_overflow() {
+        let large = Snafu::from(i128::MAX);
+        assert_eq!(i128::try_from(&large), Ok(i128::MAX));
+        assert_eq!(i128::try_from(&Snafu::from(i128::MIN)), Ok(i128::MIN));
+        assert!(i64::try_from(&large).is_err());
+        assert!(i128::try_from(&(&large + &large)).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// One of the four directions along the axes. `Up` points towards smaller `y`, like the lines of a puzzle input.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Right => self.clockwise(),
+            Turn::Left => self.clockwise().clockwise().clockwise(),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.clockwise().clockwise()
+    }
+
+    fn clockwise(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The step in x and y direction.
+    pub fn offset(self) -> (i8, i8) {
+        Direction8::from(self).offset()
+    }
+}
+
+/// One of the eight directions along the axes and the diagonals, named after the compass with north pointing towards
+/// smaller `y`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting northwards.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns by 90 degrees.
+    pub fn turn(self, turn: Turn) -> Self {
+        let index = Self::ALL.iter().position(|d| d == &self).unwrap();
+        match turn {
+            Turn::Right => Self::ALL[(index + 2) % 8],
+            Turn::Left => Self::ALL[(index + 6) % 8],
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(Turn::Right).turn(Turn::Right)
+    }
+
+    /// The step in x and y direction.
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::North,
+            Direction::Right => Direction8::East,
+            Direction::Down => Direction8::South,
+            Direction::Left => Direction8::West,
+        }
+    }
+}
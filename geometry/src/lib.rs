@@ -0,0 +1,61 @@
+use std::{fmt::Debug, hash::Hash};
+
+mod direction;
+mod point;
+mod point3;
+
+pub use direction::{Direction, Direction8, Turn};
+pub use point::Point;
+pub use point3::Point3;
+
+/// An integer usable as coordinate. All arithmetic on points goes through the checked operations, so that leaving
+/// the range of the type (like stepping left of `x = 0` on an unsigned point) is detected instead of wrapping around.
+pub trait Coordinate: Copy + Default + Ord + Hash + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn abs_diff(self, rhs: Self) -> u64;
+
+    fn checked_neg(self) -> Option<Self> {
+        Self::ZERO.checked_sub(self)
+    }
+
+    /// Moves the coordinate by -1, 0 or 1.
+    fn checked_offset(self, offset: i8) -> Option<Self> {
+        match offset {
+            0 => Some(self),
+            1 => self.checked_add(Self::ONE),
+            -1 => self.checked_sub(Self::ONE),
+            _ => unreachable!("Offsets are limited to a single step"),
+        }
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn abs_diff(self, rhs: Self) -> u64 {
+                    <$t>::abs_diff(self, rhs) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+const OVERFLOW: &str = "Coordinate overflow";
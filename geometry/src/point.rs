@@ -0,0 +1,158 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{Coordinate, Direction, Direction8, Turn, OVERFLOW};
+
+/// A point in the plane. `x` grows to the right, `y` grows downwards, like the lines of a puzzle input.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    /// The neighbor in the given direction, or `None` if it can't be represented by `T`.
+    pub fn checked_step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = direction.into().offset();
+        Some(Self::new(
+            self.x.checked_offset(dx)?,
+            self.y.checked_offset(dy)?,
+        ))
+    }
+
+    /// The direct neighbors, clockwise starting upwards. Neighbors outside the range of `T` are left out.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The direct and diagonal neighbors, clockwise starting upwards. Neighbors outside the range of `T` are left
+    /// out.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The distance when walking only along the axes.
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The distance when walking diagonally as well.
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Rotates by 90 degrees around the origin.
+    pub fn checked_rotate(self, turn: Turn) -> Option<Self> {
+        match turn {
+            Turn::Right => Some(Self::new(self.y.checked_neg()?, self.x)),
+            Turn::Left => Some(Self::new(self.y, self.x.checked_neg()?)),
+        }
+    }
+
+    pub fn rotate(self, turn: Turn) -> Self {
+        self.checked_rotate(turn).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let x = self.x.checked_neg().expect(OVERFLOW);
+        let y = self.y.checked_neg().expect(OVERFLOW);
+        Self::new(x, y)
+    }
+}
+
+impl<T: Coordinate> Add<Direction> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self.checked_step(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Direction> for Point<T> {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> Sub<Direction> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Direction) -> Self::Output {
+        self.checked_step(rhs.opposite()).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> SubAssign<Direction> for Point<T> {
+    fn sub_assign(&mut self, rhs: Direction) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> Add<Direction8> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self.checked_step(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Direction8> for Point<T> {
+    fn add_assign(&mut self, rhs: Direction8) {
+        *self = *self + rhs;
+    }
+}
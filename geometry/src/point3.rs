@@ -0,0 +1,139 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{Coordinate, Turn, OVERFLOW};
+
+/// A point or vector in space.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+            self.z.checked_add(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+            self.z.checked_sub(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_neg()?,
+            self.y.checked_neg()?,
+            self.z.checked_neg()?,
+        ))
+    }
+
+    /// The six neighbors sharing a face, along x, y and z in turn. Neighbors outside the range of `T` are left out.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy, dz)| {
+            Some(Self::new(
+                self.x.checked_offset(dx)?,
+                self.y.checked_offset(dy)?,
+                self.z.checked_offset(dz)?,
+            ))
+        })
+    }
+
+    /// The distance when walking only along the axes.
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The distance when walking diagonally as well.
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    /// Rotates by 90 degrees around the x axis. Turning left is counterclockwise when looking from the positive end of
+    /// the axis towards the origin, i.e. it follows the right-hand rule.
+    pub fn rotate_x(self, turn: Turn) -> Self {
+        let (y, z) = rotate(self.y, self.z, turn);
+        Self::new(self.x, y, z)
+    }
+
+    /// Rotates by 90 degrees around the y axis, see [`Point3::rotate_x`].
+    pub fn rotate_y(self, turn: Turn) -> Self {
+        let (z, x) = rotate(self.z, self.x, turn);
+        Self::new(x, self.y, z)
+    }
+
+    /// Rotates by 90 degrees around the z axis, see [`Point3::rotate_x`].
+    pub fn rotate_z(self, turn: Turn) -> Self {
+        let (x, y) = rotate(self.x, self.y, turn);
+        Self::new(x, y, self.z)
+    }
+}
+
+/// Rotates `(a, b)` within the plane spanned by two axes, where turning left moves `a` towards `b`.
+fn rotate<T: Coordinate>(a: T, b: T, turn: Turn) -> (T, T) {
+    match turn {
+        Turn::Left => (b.checked_neg().expect(OVERFLOW), a),
+        Turn::Right => (b, a.checked_neg().expect(OVERFLOW)),
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect(OVERFLOW)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect(OVERFLOW)
+    }
+}
@@ -0,0 +1,87 @@
+//! Arithmetic on points and directions, and where it leaves the range of the coordinates.
+
+use geometry::{Direction, Direction8, Point, Point3, Turn};
+
+#[test]
+fn points_add_and_subtract_component_wise() {
+    let (a, b) = (Point::new(3, -2), Point::new(-1, 5));
+    assert_eq!(a + b, Point::new(2, 3));
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(-a, Point::new(-3, 2));
+    assert_eq!(Point::new(1, 2) + Direction::Up, Point::new(1, 1));
+    assert_eq!(Point::new(1, 2) - Direction::Left, Point::new(2, 2));
+    assert_eq!(Point::new(1, 2) + Direction8::SouthWest, Point::new(0, 3));
+}
+
+#[test]
+fn checked_operations_stay_within_the_coordinates() {
+    let origin = Point::<u8>::new(0, 0);
+    assert_eq!(origin.checked_step(Direction::Left), None);
+    assert_eq!(origin.checked_step(Direction::Down), Some(Point::new(0, 1)));
+    assert_eq!(origin.checked_sub(Point::new(0, 1)), None);
+    assert_eq!(Point::new(u8::MAX, 0).checked_add(Point::new(1, 0)), None);
+    assert_eq!(
+        origin.neighbors4().collect::<Vec<_>>(),
+        [Point::new(1, 0), Point::new(0, 1)]
+    );
+    assert_eq!(origin.neighbors8().count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Coordinate overflow")]
+fn operators_panic_when_leaving_the_coordinates() {
+    let _ = Point::<u8>::new(0, 0) + Direction::Up;
+}
+
+#[test]
+fn neighbors_go_clockwise_starting_upwards() {
+    let neighbors = Point::new(0, 0).neighbors4().collect::<Vec<_>>();
+    assert_eq!(
+        neighbors,
+        [
+            Point::new(0, -1),
+            Point::new(1, 0),
+            Point::new(0, 1),
+            Point::new(-1, 0)
+        ]
+    );
+    assert_eq!(
+        Point::new(0, 0).neighbors8().nth(1),
+        Some(Point::new(1, -1))
+    );
+    assert_eq!(Point3::new(0, 0, 0).neighbors6().count(), 6);
+}
+
+#[test]
+fn distances() {
+    let (a, b) = (Point::new(1, 1), Point::new(4, -3));
+    assert_eq!(a.manhattan_distance(b), 7);
+    assert_eq!(a.chebyshev_distance(b), 4);
+    let (a, b) = (Point3::new(0, 0, 0), Point3::new(1, -2, 3));
+    assert_eq!(a.manhattan_distance(b), 6);
+    assert_eq!(a.chebyshev_distance(b), 3);
+}
+
+#[test]
+fn turns_and_rotations() {
+    assert_eq!(Direction::Up.turn(Turn::Right), Direction::Right);
+    assert_eq!(Direction::Up.turn(Turn::Left), Direction::Left);
+    assert_eq!(Direction::Down.opposite(), Direction::Up);
+    assert_eq!(
+        Direction8::NorthEast.turn(Turn::Right),
+        Direction8::SouthEast
+    );
+    assert_eq!(Direction8::NorthEast.opposite(), Direction8::SouthWest);
+    // Turning right is clockwise on the screen, where y grows downwards
+    assert_eq!(Point::new(0, -1).rotate(Turn::Right), Point::new(1, 0));
+    assert_eq!(Point::new(1, 0).rotate(Turn::Left), Point::new(0, -1));
+    assert_eq!(Point::<u8>::new(0, 1).checked_rotate(Turn::Right), None);
+    assert_eq!(
+        Point3::new(0, 1, 0).rotate_x(Turn::Left),
+        Point3::new(0, 0, 1)
+    );
+    assert_eq!(
+        Point3::new(1, 0, 0).rotate_z(Turn::Left),
+        Point3::new(0, 1, 0)
+    );
+}
@@ -0,0 +1,209 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::parse::{self, Failure, Parsed};
+use geometry::Direction8;
+
+use crate::Point;
+
+/// A rectangular grid, storing a value for every position.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map, one row per line like [`parse::lines`]. Lines shorter than the longest one are padded
+    /// with spaces, `f` returns `None` for characters that are not valid on the map, which are reported as not being
+    /// `expected`.
+    pub fn parse<'a>(
+        text: &'a str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Parsed<'a, Self> {
+        let (rows, rest) = parse::lines(text, parse::rest_of_line)?;
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in &rows {
+            for (index, c) in row.char_indices() {
+                let token = &row[index..index + c.len_utf8()];
+                cells.push(f(c).ok_or_else(|| Failure::new(token, expected))?);
+            }
+            for _ in row.chars().count()..width {
+                let end = &row[row.len()..];
+                cells.push(f(' ').ok_or_else(|| Failure::new(end, expected))?);
+            }
+        }
+        let grid = Self {
+            width,
+            height: rows.len(),
+            cells,
+        };
+        Ok((grid, rest))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|index| self.point_of(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.point_of(index), value))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Walks from `start` (exclusive) into `direction`, until the edge of the grid is reached.
+    pub fn ray(&self, start: Point, direction: impl Into<Direction8>) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            position: start,
+            direction: direction.into(),
+        }
+    }
+
+    /// The direct neighbors of `point` that lie on the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors4()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The direct and diagonal neighbors of `point` that lie on the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors8()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The first position, row by row, whose value matches the predicate.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.point_of(index))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.get(index).expect("Position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        self.get_mut(index).expect("Position outside of the grid")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    position: Point,
+    direction: Direction8,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.position += self.direction;
+        self.grid
+            .get(self.position)
+            .map(|value| (self.position, value))
+    }
+}
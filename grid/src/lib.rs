@@ -0,0 +1,8 @@
+mod dense;
+mod sparse;
+
+pub use dense::{Grid, Ray};
+pub use sparse::SparseGrid;
+
+/// A position on a grid. `x` grows to the right, `y` grows downwards, like the lines of a puzzle input.
+pub type Point = geometry::Point<i64>;
@@ -0,0 +1,144 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+};
+
+use common::parse::{self, Parsed};
+
+use crate::Point;
+
+/// An unbounded grid, storing values only for the occupied positions.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a character map, one row per line like [`parse::grid`]. Characters equal to `empty` are left out, `f`
+    /// returns `None` for characters that are not valid on the map, which are reported as not being `expected`.
+    pub fn parse<'a>(
+        text: &'a str,
+        empty: char,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Parsed<'a, Self> {
+        let (rows, rest) = parse::grid(text, expected, |c| match c == empty {
+            true => Some(None),
+            false => f(c).map(Some),
+        })?;
+        let cells = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter().enumerate().filter_map(move |(x, value)| {
+                    value.map(|value| (Point::new(x as i64, y as i64), value))
+                })
+            })
+            .collect();
+        Ok((Self { cells }, rest))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn entry(&mut self, point: Point) -> Entry<'_, Point, T> {
+        self.cells.entry(point)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The smallest and the largest corner of the rectangle enclosing all occupied positions.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.cells.keys().fold(None, |bounds, point| {
+            let (min, max) = bounds.unwrap_or((*point, *point));
+            Some((
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ))
+        })
+    }
+
+    /// The occupied direct neighbors of `point`.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbors4()
+            .filter_map(|neighbor| self.get(neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// The occupied direct and diagonal neighbors of `point`.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbors8()
+            .filter_map(|neighbor| self.get(neighbor).map(|value| (neighbor, value)))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        Self {
+            cells: HashMap::from_iter(iter),
+        }
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+/// Renders the rectangle enclosing all occupied positions, empty positions are shown as `.`.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
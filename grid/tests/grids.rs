@@ -0,0 +1,88 @@
+//! Bounds and neighbors of the dense and the sparse grid.
+
+use common::parse;
+use geometry::{Direction, Direction8};
+use grid::{Grid, Point, SparseGrid};
+
+fn digits(text: &str) -> Grid<u32> {
+    parse::complete(text, |text| {
+        Grid::parse(text, "a digit", |c| c.to_digit(10))
+    })
+    .unwrap()
+}
+
+#[test]
+fn parses_rows_of_cells() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+    assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), [1, 4]);
+    assert_eq!(grid.position(|digit| *digit > 4), Some(Point::new(1, 1)));
+
+    let error = parse::complete("12\n3x\n", |text| {
+        Grid::parse(text, "a digit", |c| c.to_digit(10))
+    });
+    let error = error.err().unwrap();
+    assert_eq!(
+        (error.line, error.column, error.expected.as_str()),
+        (2, 2, "a digit")
+    );
+}
+
+#[test]
+fn positions_outside_are_not_on_the_grid() {
+    let grid = digits("12\n34\n");
+    for point in [
+        Point::new(-1, 0),
+        Point::new(0, -1),
+        Point::new(2, 0),
+        Point::new(0, 2),
+    ] {
+        assert!(!grid.contains(point));
+        assert_eq!(grid.get(point), None);
+    }
+    assert_eq!(grid.get(Point::new(1, 1)), Some(&4));
+}
+
+#[test]
+fn neighbors_on_the_grid_only() {
+    let grid = digits("123\n456\n789\n");
+    assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    assert_eq!(
+        grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+        [Point::new(1, 0), Point::new(0, 1)]
+    );
+    assert_eq!(grid.neighbors8(Point::new(2, 2)).count(), 3);
+}
+
+#[test]
+fn rays_stop_at_the_edge() {
+    let grid = digits("123\n456\n789\n");
+    let values = |start, direction: Direction8| {
+        grid.ray(start, direction)
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(Point::new(0, 0), Direction::Right.into()), [2, 3]);
+    assert_eq!(values(Point::new(0, 0), Direction8::SouthEast), [5, 9]);
+    assert!(values(Point::new(0, 0), Direction::Up.into()).is_empty());
+}
+
+#[test]
+fn sparse_grids_have_bounds_and_occupied_neighbors() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    grid.insert(Point::new(2, -1), 'a');
+    grid.insert(Point::new(-3, 4), 'b');
+    grid.insert(Point::new(3, 0), 'c');
+    assert_eq!(grid.bounds(), Some((Point::new(-3, -1), Point::new(3, 4))));
+    assert_eq!(grid.neighbors4(Point::new(2, 0)).count(), 2);
+    assert_eq!(
+        grid.neighbors8(Point::new(3, -1))
+            .map(|(_, c)| *c)
+            .collect::<Vec<_>>(),
+        ['c', 'a']
+    );
+}
@@ -0,0 +1,47 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use common::stats::SearchStats;
+
+use crate::path;
+
+/// The path with the fewest steps from any of the starts to a goal, both included, by breadth-first search.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    stats: &mut SearchStats,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        if parents.insert(start.clone(), None).is_none() {
+            frontier.push_back(start);
+        }
+    }
+    stats.frontier(frontier.len());
+
+    while let Some(node) = frontier.pop_front() {
+        stats.expanded += 1;
+        if is_goal(&node) {
+            return Some(path(node, |node| parents[node].clone()));
+        }
+        for neighbor in neighbors(&node) {
+            match parents.entry(neighbor) {
+                Entry::Occupied(_) => stats.duplicates += 1,
+                Entry::Vacant(entry) => {
+                    frontier.push_back(entry.key().clone());
+                    entry.insert(Some(node.clone()));
+                }
+            }
+        }
+        stats.frontier(frontier.len());
+    }
+    None
+}
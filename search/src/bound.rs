@@ -0,0 +1,135 @@
+use common::{checkpoint::SearchCheckpoint, progress::Progress, stats::SearchStats};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// How far a branch and bound search got: everything needed to continue it.
+#[derive(Serialize, Deserialize)]
+struct State<N, V> {
+    /// The highest value found so far, with the path to its node.
+    best: (V, Vec<N>),
+    /// The nodes still to try, with their depth.
+    frontier: Vec<(usize, N)>,
+    /// The path to the node tried last.
+    path: Vec<N>,
+    /// The number of branches of the start, to tell how far the search got.
+    roots: usize,
+}
+
+/// The node with the highest value reachable from the start, with the path to it, by depth-first search. The
+/// branches of a node are tried in the order they are given. A node is skipped with all of its branches if its bound
+/// is not above the best value found so far, so the bound must never be below the value of the node or of any node
+/// reachable from it. If several nodes have the highest value, the first one found wins.
+pub fn branch_and_bound<N, V, I>(
+    start: N,
+    branches: impl FnMut(&N) -> I,
+    mut value: impl FnMut(&N) -> V,
+    bound: impl FnMut(&N) -> V,
+    stats: &mut SearchStats,
+) -> (V, Vec<N>)
+where
+    N: Clone,
+    V: Ord + Copy,
+    I: IntoIterator<Item = N>,
+{
+    let state = start_state(start, &mut value);
+    let progress = &mut Progress::ignored();
+    search(state, branches, value, bound, stats, |_| {}, progress).best
+}
+
+/// Like [`branch_and_bound`], saving its state to the checkpoint and continuing from the state saved there before. A
+/// finished search is saved as well, so it is not repeated when resuming. The search reports how many branches of the
+/// start it went through, and stops when the progress is cancelled, with the best node found until then.
+pub fn resumable_branch_and_bound<N, V, I>(
+    start: N,
+    branches: impl FnMut(&N) -> I,
+    mut value: impl FnMut(&N) -> V,
+    bound: impl FnMut(&N) -> V,
+    stats: &mut SearchStats,
+    mut checkpoint: SearchCheckpoint,
+    progress: &mut Progress,
+) -> (V, Vec<N>)
+where
+    N: Clone + Serialize + DeserializeOwned,
+    V: Ord + Copy + Serialize + DeserializeOwned,
+    I: IntoIterator<Item = N>,
+{
+    let state = checkpoint
+        .progress()
+        .unwrap_or_else(|| start_state(start, &mut value));
+    let report = |state: &State<N, V>| checkpoint.update(|| state);
+    let state = search(state, branches, value, bound, stats, report, progress);
+    checkpoint.save(&state);
+    state.best
+}
+
+fn start_state<N: Clone, V>(start: N, value: impl FnOnce(&N) -> V) -> State<N, V> {
+    State {
+        best: (value(&start), vec![start.clone()]),
+        frontier: vec![(0, start)],
+        path: Vec::new(),
+        roots: 0,
+    }
+}
+
+/// Continues the search until the frontier is empty or the progress is cancelled, passing the state to `save` before
+/// each node.
+fn search<N, V, I>(
+    mut state: State<N, V>,
+    mut branches: impl FnMut(&N) -> I,
+    mut value: impl FnMut(&N) -> V,
+    mut bound: impl FnMut(&N) -> V,
+    stats: &mut SearchStats,
+    mut save: impl FnMut(&State<N, V>),
+    progress: &mut Progress,
+) -> State<N, V>
+where
+    N: Clone,
+    V: Ord + Copy,
+    I: IntoIterator<Item = N>,
+{
+    stats.frontier(state.frontier.len());
+
+    loop {
+        save(&state);
+        if progress.is_cancelled() {
+            break;
+        }
+        let State {
+            best,
+            frontier,
+            path,
+            roots,
+        } = &mut state;
+        let Some((depth, node)) = frontier.pop() else {
+            progress.report(1.0);
+            break;
+        };
+        if depth == 1 {
+            // The branches of the start stay at the bottom of the frontier until they are taken
+            let left = frontier.iter().take_while(|(depth, _)| *depth == 1).count();
+            progress.report((*roots - left - 1) as f64 / *roots as f64);
+        }
+        // The best value may have grown since the node was put on the frontier
+        if depth > 0 && bound(&node) <= best.0 {
+            stats.pruned += 1;
+            continue;
+        }
+        stats.expanded += 1;
+        path.truncate(depth);
+        path.push(node);
+        let node = path.last().unwrap();
+
+        let node_value = value(node);
+        if node_value > best.0 {
+            *best = (node_value, path.clone());
+        }
+        let first = frontier.len();
+        frontier.extend(branches(node).into_iter().map(|branch| (depth + 1, branch)));
+        // The last branch is taken from the frontier first
+        frontier[first..].reverse();
+        if depth == 0 {
+            *roots = frontier.len() - first;
+        }
+        stats.frontier(frontier.len());
+    }
+    state
+}
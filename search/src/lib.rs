@@ -0,0 +1,21 @@
+//! Searches over graphs which are only given by closures: the nodes are whatever the puzzle needs, the edges are
+//! created on the fly while searching. Every search counts the nodes it goes through into [`SearchStats`].
+
+mod bfs;
+mod bound;
+mod shortest;
+
+pub use bfs::bfs;
+pub use bound::{branch_and_bound, resumable_branch_and_bound};
+pub use common::{checkpoint::Checkpoint, progress::Progress, stats::SearchStats};
+pub use shortest::{astar, dijkstra};
+
+/// The nodes from a start to the node, both included, following the parents backwards.
+fn path<N>(node: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut path = vec![node];
+    while let Some(parent) = parent(path.last().unwrap()) {
+        path.push(parent);
+    }
+    path.reverse();
+    path
+}
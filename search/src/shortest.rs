@@ -0,0 +1,79 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+use common::stats::SearchStats;
+
+use crate::path;
+
+/// The cheapest path from any of the starts to a goal, both included, with its cost. The neighbors come with the
+/// cost of the step to them. Costs must not be negative, `C::default()` is taken as zero.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+    stats: &mut SearchStats,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal, stats)
+}
+
+/// Like [`dijkstra`], but nodes which are estimated to be closer to a goal are tried first. The estimate must never
+/// be above the cost of the cheapest path from the node to a goal, otherwise the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut estimate: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    stats: &mut SearchStats,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The cheapest cost found so far for every node reached, with the node before it on that path
+    let mut best = HashMap::<N, (C, Option<N>)>::new();
+    // The frontier only holds numbers into the queued nodes, so that the nodes do not need to be ordered
+    let mut queued = Vec::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if best.insert(start.clone(), (C::default(), None)).is_none() {
+            frontier.push(Reverse((estimate(&start), C::default(), queued.len())));
+            queued.push(start);
+        }
+    }
+    stats.frontier(frontier.len());
+
+    while let Some(Reverse((_, cost, number))) = frontier.pop() {
+        let node = queued[number].clone();
+        // A cheaper path to the node was found after this one had been queued
+        if best[&node].0 < cost {
+            stats.duplicates += 1;
+            continue;
+        }
+        stats.expanded += 1;
+        if is_goal(&node) {
+            return Some((path(node, |node| best[node].1.clone()), cost));
+        }
+        for (neighbor, step) in neighbors(&node) {
+            let cost = cost + step;
+            if best.get(&neighbor).is_some_and(|(known, _)| *known <= cost) {
+                stats.duplicates += 1;
+                continue;
+            }
+            frontier.push(Reverse((cost + estimate(&neighbor), cost, queued.len())));
+            best.insert(neighbor.clone(), (cost, Some(node.clone())));
+            queued.push(neighbor);
+        }
+        stats.frontier(frontier.len());
+    }
+    None
+}
@@ -0,0 +1,172 @@
+//! The searches on small graphs whose best paths are known.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use common::{config::Params, progress::CancellationToken, Part};
+use search::{
+    astar, bfs, branch_and_bound, dijkstra, resumable_branch_and_bound, Checkpoint, Progress,
+    SearchStats,
+};
+
+/// A weighted graph of five nodes: the direct edge from 0 to 4 is the most expensive way there.
+fn edges(node: &u8) -> Vec<(u8, u32)> {
+    match node {
+        0 => vec![(1, 1), (2, 4), (4, 10)],
+        1 => vec![(2, 1), (3, 6)],
+        2 => vec![(3, 1)],
+        3 => vec![(4, 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn bfs_finds_the_fewest_steps() {
+    let neighbors = |node: &u8| edges(node).into_iter().map(|(node, _)| node);
+    let mut stats = SearchStats::default();
+    assert_eq!(
+        bfs([0], neighbors, |node| *node == 4, &mut stats),
+        Some(vec![0, 4])
+    );
+    assert_eq!(bfs([2], neighbors, |node| *node == 1, &mut stats), None);
+    assert!(stats.duplicates > 0);
+}
+
+#[test]
+fn dijkstra_and_astar_find_the_cheapest_path() {
+    let mut stats = SearchStats::default();
+    let cheapest = Some((vec![0, 1, 2, 3, 4], 4));
+    assert_eq!(
+        dijkstra([0], edges, |node| *node == 4, &mut stats),
+        cheapest
+    );
+    // Never above the cost of the rest of the cheapest path
+    let estimate = |node: &u8| 4 - *node as u32;
+    assert_eq!(
+        astar([0], edges, estimate, |node| *node == 4, &mut stats),
+        cheapest
+    );
+    assert_eq!(dijkstra([4], edges, |node| *node == 0, &mut stats), None);
+}
+
+#[test]
+fn branch_and_bound_finds_the_best_node_and_prunes() {
+    // Picks up to three of the numbers in order, the sum of them is the value
+    let numbers = [5, 1, 7, 2, 3];
+    let branches = |picked: &Vec<usize>| match picked.len() {
+        3 => vec![],
+        _ => (picked.last().map_or(0, |last| last + 1)..numbers.len())
+            .map(|next| [picked.as_slice(), &[next]].concat())
+            .collect(),
+    };
+    let value = |picked: &Vec<usize>| picked.iter().map(|index| numbers[*index]).sum::<u32>();
+    let bound = |picked: &Vec<usize>| value(picked) + 7 * (3 - picked.len() as u32);
+
+    let mut stats = SearchStats::default();
+    let (best, path) = branch_and_bound(vec![], branches, value, bound, &mut stats);
+    assert_eq!(best, 15);
+    assert_eq!(path.last(), Some(&vec![0, 2, 4]));
+    assert!(stats.pruned > 0);
+}
+
+#[test]
+fn branch_and_bound_resumes_from_a_checkpoint() {
+    // Picks up to five of the numbers in order, without a bound to prune anything
+    let numbers = [3, 9, 4, 1, 7, 12, 2, 8, 5, 11, 6, 10];
+    let branches = |picked: &Vec<usize>| match picked.len() {
+        5 => vec![],
+        _ => (picked.last().map_or(0, |last| last + 1)..numbers.len())
+            .map(|next| [picked.as_slice(), &[next]].concat())
+            .collect(),
+    };
+    let value = |picked: &Vec<usize>| picked.iter().map(|index| numbers[*index]).sum::<u32>();
+    let (best, _) = branch_and_bound(
+        vec![],
+        branches,
+        value,
+        |_| u32::MAX,
+        &mut SearchStats::default(),
+    );
+
+    let path = std::env::temp_dir().join(format!("checkpoint-{}.json", std::process::id()));
+    let mut checkpoint =
+        Checkpoint::create(&path, 0, Part::One, "", &Params::new(), Duration::ZERO).unwrap();
+    // Interrupted somewhere after the first save
+    let mut expanded = 0;
+    let interrupted = panic::catch_unwind(AssertUnwindSafe(|| {
+        let branches = |picked: &Vec<usize>| {
+            expanded += 1;
+            assert!(expanded < 1500, "interrupted");
+            branches(picked)
+        };
+        let stats = &mut SearchStats::default();
+        resumable_branch_and_bound(
+            vec![],
+            branches,
+            value,
+            |_| u32::MAX,
+            stats,
+            checkpoint.search("numbers"),
+            &mut Progress::ignored(),
+        )
+    }));
+    assert!(interrupted.is_err());
+
+    let mut checkpoint =
+        Checkpoint::resume(&path, 0, Part::One, "", &Params::new(), Duration::ZERO).unwrap();
+    let mut stats = SearchStats::default();
+    let resumed = resumable_branch_and_bound(
+        vec![],
+        branches,
+        value,
+        |_| u32::MAX,
+        &mut stats,
+        checkpoint.search("numbers"),
+        &mut Progress::ignored(),
+    );
+    assert_eq!(resumed.0, best);
+    assert!(stats.expanded < 1500);
+    checkpoint.finish().unwrap();
+    assert!(!path.exists());
+}
+
+#[test]
+fn branch_and_bound_stops_when_cancelled() {
+    // Picks three of the numbers in order, the best ones come last
+    let numbers = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let branches = |picked: &Vec<usize>| match picked.len() {
+        3 => vec![],
+        _ => (picked.last().map_or(0, |last| last + 1)..numbers.len())
+            .map(|next| [picked.as_slice(), &[next]].concat())
+            .collect(),
+    };
+    let value = |picked: &Vec<usize>| picked.iter().map(|index| numbers[*index]).sum::<u32>();
+
+    let token = CancellationToken::new();
+    let mut reported = vec![];
+    let mut progress = Progress::new(token.clone(), |done| reported.push(done));
+    let mut expanded = 0;
+    let branches = |picked: &Vec<usize>| {
+        expanded += 1;
+        if expanded == 20 {
+            token.cancel();
+        }
+        branches(picked)
+    };
+    let (best, path) = resumable_branch_and_bound(
+        vec![],
+        branches,
+        value,
+        |_| u32::MAX,
+        &mut SearchStats::default(),
+        Checkpoint::disabled().search("numbers"),
+        &mut progress,
+    );
+    drop(progress);
+    assert_eq!(expanded, 20);
+    assert_eq!(path.last().map(value), Some(best));
+    assert!(best > 0 && best < 27);
+    assert!(!reported.is_empty() && reported.iter().all(|done| *done < 1.0));
+}